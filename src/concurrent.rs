@@ -0,0 +1,187 @@
+//! A [`ConcurrentQueue`] is a thread-safe First In First Out (FIFO) linear structure.
+//!
+//! It wraps a [`Queue`] behind a [`Mutex`] so it can be shared across threads
+//! behind an [`Arc`](std::sync::Arc), the lock is never exposed to the caller.
+//!
+
+use crate::queue::Queue;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// ConcurrentQueue Struct
+#[derive(Debug)]
+pub struct ConcurrentQueue<T>(Mutex<Queue<T>>);
+
+impl<T> ConcurrentQueue<T> {
+    /// Creates a new [`ConcurrentQueue`]
+    ///
+    /// # Example
+    /// Creating a new [`ConcurrentQueue`] of `i32`
+    /// ```
+    /// use linked_lists_rs::concurrent::ConcurrentQueue;
+    /// let queue: ConcurrentQueue<i32> = ConcurrentQueue::new();
+    /// ```
+    pub const fn new() -> Self {
+        ConcurrentQueue(Mutex::new(Queue::new()))
+    }
+
+    /// Push a new value on the end of the [`ConcurrentQueue`]
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::concurrent::ConcurrentQueue;
+    /// let queue = ConcurrentQueue::new();
+    ///
+    /// queue.push(5);
+    ///
+    /// assert_eq!(Some(5), queue.pop());
+    /// ```
+    pub fn push(&self, value: T) {
+        self.lock().push(value);
+    }
+
+    /// Pops and return the value on the front of the [`ConcurrentQueue`]
+    /// Returns `None` if the [`ConcurrentQueue`] is empty
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::concurrent::ConcurrentQueue;
+    /// let queue = ConcurrentQueue::new();
+    ///
+    /// queue.push(5);
+    ///
+    /// assert_eq!(Some(5), queue.pop());
+    /// assert_eq!(None, queue.pop());
+    /// ```
+    pub fn pop(&self) -> Option<T> {
+        self.lock().pop()
+    }
+
+    /// Calls `f` with a reference to the value on the front of the [`ConcurrentQueue`]
+    /// `f` receives `None` if the [`ConcurrentQueue`] is empty
+    /// The lock is held while `f` runs
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::concurrent::ConcurrentQueue;
+    /// let queue = ConcurrentQueue::new();
+    ///
+    /// queue.push(5);
+    ///
+    /// assert_eq!(Some(10), queue.peek(|front| front.map(|x| x * 2)));
+    /// assert_eq!(Some(5), queue.pop());
+    /// ```
+    pub fn peek<R, F: FnOnce(Option<&T>) -> R>(&self, f: F) -> R {
+        f(self.lock().peek())
+    }
+
+    /// Return the number of elements in the [`ConcurrentQueue`]
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::concurrent::ConcurrentQueue;
+    /// let queue = ConcurrentQueue::new();
+    ///
+    /// queue.push(5);
+    ///
+    /// assert_eq!(1, queue.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Return `true` if the [`ConcurrentQueue`] contains no elements
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::concurrent::ConcurrentQueue;
+    /// let queue = ConcurrentQueue::new();
+    /// assert!(queue.is_empty());
+    ///
+    /// queue.push(5);
+    /// assert!(!queue.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    // A panic while holding the lock can't leave the queue in an invalid state,
+    // so a poisoned lock is recovered instead of propagating the panic.
+    fn lock(&self) -> MutexGuard<'_, Queue<T>> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> Default for ConcurrentQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ConcurrentQueue;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn basics() {
+        let list = ConcurrentQueue::new();
+        assert_eq!(list.pop(), None);
+        assert!(list.is_empty());
+
+        list.push(1);
+        list.push(2);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.peek(|front| front.copied()), Some(1));
+
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), None);
+        assert_eq!(list.peek(|front| front.copied()), None);
+    }
+
+    #[test]
+    fn producers_and_consumer() {
+        const PRODUCERS: usize = 4;
+        const PER_PRODUCER: usize = 1000;
+
+        let list = Arc::new(ConcurrentQueue::new());
+
+        let producers: Vec<_> = (0..PRODUCERS)
+            .map(|p| {
+                let list = Arc::clone(&list);
+                thread::spawn(move || {
+                    for x in 0..PER_PRODUCER {
+                        list.push(p * PER_PRODUCER + x);
+                    }
+                })
+            })
+            .collect();
+
+        let consumer = {
+            let list = Arc::clone(&list);
+            thread::spawn(move || {
+                let mut seen = vec![false; PRODUCERS * PER_PRODUCER];
+                let mut count = 0;
+                while count < seen.len() {
+                    match list.pop() {
+                        Some(x) => {
+                            assert!(!seen[x], "{x} popped twice");
+                            seen[x] = true;
+                            count += 1;
+                        }
+                        None => thread::yield_now(),
+                    }
+                }
+                seen
+            })
+        };
+
+        for producer in producers {
+            producer.join().unwrap();
+        }
+        let seen = consumer.join().unwrap();
+        assert!(seen.into_iter().all(|x| x));
+        assert!(list.is_empty());
+    }
+}
@@ -1,4 +1,5 @@
-use std::sync::Arc;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
 
 // Arc make it thread safe if T: Send + Sync
 type Link<T> = Option<Arc<Node<T>>>;
@@ -13,7 +14,7 @@ struct Node<T> {
 }
 
 impl<T> List<T> {
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         List(None)
     }
 
@@ -33,22 +34,210 @@ impl<T> List<T> {
     pub fn head(&self) -> Option<&T> {
         self.0.as_ref().map(|node| &node.value)
     }
+
+    /// Maps the list where `f` returning `None` keeps the existing value,
+    /// the suffix after the last replaced value is shared with `self` instead of rebuilt.
+    pub fn map_shared<F: FnMut(&T) -> Option<T>>(&self, mut f: F) -> Self
+    where
+        T: Clone,
+    {
+        let nodes: Vec<&Arc<Node<T>>> =
+            core::iter::successors(self.0.as_ref(), |node| node.next.as_ref()).collect();
+        let mut values: Vec<Option<T>> = nodes.iter().map(|node| f(&node.value)).collect();
+
+        let Some(last_changed) = values.iter().rposition(Option::is_some) else {
+            return List(self.0.clone());
+        };
+        values.truncate(last_changed + 1);
+
+        let mut list = List(nodes[last_changed].next.clone());
+        for (node, value) in nodes.iter().zip(values).rev() {
+            list = list.push(value.unwrap_or_else(|| node.value.clone()));
+        }
+        list
+    }
+
+    /// Builds a new list applying `f` to each value, keeping the head to tail order.
+    pub fn map<U, F: Fn(&T) -> U>(&self, f: F) -> List<U> {
+        self.iter().map(f).collect()
+    }
+
+    /// Builds a new list with `sep` inserted between each pair of adjacent values.
+    pub fn intersperse(&self, sep: T) -> Self
+    where
+        T: Clone,
+    {
+        let values: Vec<&T> = self.iter().collect();
+        let Some((last, rest)) = values.split_last() else {
+            return List::new();
+        };
+        rest.iter()
+            .rev()
+            .fold(List::new().push((*last).clone()), |list, value| {
+                list.push(sep.clone()).push((*value).clone())
+            })
+    }
+
+    /// Returns the tail-most value, the oldest one, walking the whole list.
+    pub fn last(&self) -> Option<&T> {
+        self.iter().last()
+    }
+
+    /// Clones every value, head to tail, into a detached `Vec` which can be freely mutated,
+    /// the list and any list sharing its nodes are never affected.
+    pub fn to_mut_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    /// Iterates over overlapping windows of `size` values, head to tail,
+    /// yields nothing if `size` is greater than the length. Panics if `size` is zero.
+    pub fn windows(&self, size: usize) -> impl Iterator<Item = Vec<&T>> {
+        assert!(size != 0, "window size must be non-zero");
+
+        let mut node = self.0.as_deref();
+        core::iter::from_fn(move || {
+            let window: Vec<&T> = Iter(node).take(size).collect();
+            if window.len() < size {
+                return None;
+            }
+            node = node.and_then(|node| node.next.as_deref());
+            Some(window)
+        })
+    }
+
+    /// Skips the leading values matching `pred`, the returned list shares
+    /// the remaining nodes with `self` so nothing is cloned.
+    pub fn drop_while<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Self {
+        let mut link = &self.0;
+        while let Some(node) = link {
+            if !pred(&node.value) {
+                break;
+            }
+            link = &node.next;
+        }
+        List(link.clone())
+    }
+
+    /// Builds a new list from the leading values matching `pred`,
+    /// the values are cloned as the prefix can't share the nodes of `self`.
+    pub fn take_while<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Self
+    where
+        T: Clone,
+    {
+        self.iter()
+            .take_while(|value| pred(value))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the value `index` nodes away from the head, `0` being the head,
+    /// or `None` if the list is shorter.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
+    }
+
+    /// Counts the values matching `pred`, walking the whole list.
+    pub fn count<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        self.iter().filter(|value| pred(value)).count()
+    }
+
+    /// Compares the values, head to tail, with `slice`, lists of different lengths are never equal.
+    pub fn eq_slice(&self, slice: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().eq(slice)
+    }
+
+    /// Builds a new list with the values of `self` followed by the values of `other`,
+    /// the values of `self` are cloned while `other` is shared as the tail.
+    pub fn append(&self, other: &List<T>) -> Self
+    where
+        T: Clone,
+    {
+        let values: Vec<&T> = self.iter().collect();
+        values
+            .into_iter()
+            .rev()
+            .fold(List(other.0.clone()), |list, value| {
+                list.push(value.clone())
+            })
+    }
+
+    /// Iterates over the values paired with their index, the head being `0`,
+    /// indices are relative to this list so a shared tail starts again from `0`.
+    pub fn enumerate(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.iter().enumerate()
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 // Custom code within the destructor.
+// Unwinds uniquely owned nodes iteratively to avoid a recursive drop on long lists.
+// `Arc::into_inner` hands the node to exactly one of the lists dropping it, even when
+// they race on different threads, so a shared chain is never left to a recursive drop.
 impl<T> Drop for List<T> {
     fn drop(&mut self) {
         let mut head = self.0.take();
         while let Some(node) = head {
-            if let Ok(mut node) = Arc::try_unwrap(node) {
-                head = node.next.take();
-            } else {
-                break;
+            match Arc::into_inner(node) {
+                Some(mut node) => head = node.next.take(),
+                None => break,
+            }
+        }
+    }
+}
+
+// The first yielded value becomes the head, so collecting `iter()` keeps the same order.
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let values: Vec<T> = iter.into_iter().collect();
+        values
+            .into_iter()
+            .rev()
+            .fold(List::new(), |list, value| list.push(value))
+    }
+}
+
+// IntoIter for owned iteration
+// Values of uniquely owned nodes are moved out, shared nodes are left intact and cloned.
+pub struct IntoIter<T>(List<T>);
+
+impl<T: Clone> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.0 .0.take()?;
+        match Arc::try_unwrap(node) {
+            Ok(mut node) => {
+                self.0 .0 = node.next.take();
+                Some(node.value)
+            }
+            Err(node) => {
+                self.0 .0 = node.next.clone();
+                Some(node.value.clone())
             }
         }
     }
 }
 
+impl<T: Clone> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
 // Iter for referenced iteration
 pub struct Iter<'a, T>(Option<&'a Node<T>>);
 
@@ -63,6 +252,13 @@ impl<'a, T> Iterator for Iter<'a, T> {
     }
 }
 
+// Manual impl as deriving would needlessly require `T: Clone`.
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter(self.0)
+    }
+}
+
 impl<T> List<T> {
     pub fn iter(&self) -> Iter<'_, T> {
         let node = self.0.as_deref();
@@ -73,6 +269,7 @@ impl<T> List<T> {
 #[cfg(test)]
 mod tests {
     use super::List;
+    use std::sync::Arc;
 
     #[test]
     fn creates_an_empty_list() {
@@ -120,4 +317,348 @@ mod tests {
         assert_eq!(iter.next(), Some(&1));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn const_new() {
+        static LIST: List<i32> = List::new();
+        assert_eq!(LIST.head(), None);
+        assert_eq!(LIST.push(1).head(), Some(&1));
+    }
+
+    #[test]
+    fn drops_long_list() {
+        let list = (0..100_000).fold(List::new(), |list, x| list.push(x));
+        assert_eq!(list.head(), Some(&99_999));
+        drop(list);
+    }
+
+    #[test]
+    fn drops_shared_suffix() {
+        let token = Arc::new(());
+        let base = (0..100_000).fold(List::new(), |list, _| list.push(token.clone()));
+        let a = (0..100_000).fold(base.tail(), |list, _| list.push(token.clone()));
+        let b = base.push(token.clone());
+        assert_eq!(Arc::strong_count(&token), 200_002);
+
+        let shared = base.0.as_ref().unwrap();
+        assert_eq!(Arc::strong_count(shared), 2);
+        let shared_tail = base.tail();
+        assert_eq!(Arc::strong_count(shared_tail.0.as_ref().unwrap()), 3);
+
+        // Dropping a list keeps the nodes still shared with others
+        drop(a);
+        assert_eq!(Arc::strong_count(&token), 100_002);
+        assert_eq!(Arc::strong_count(shared_tail.0.as_ref().unwrap()), 2);
+        assert_eq!(base.iter().count(), 100_000);
+
+        drop(b);
+        drop(shared_tail);
+        assert_eq!(Arc::strong_count(&token), 100_001);
+        assert_eq!(Arc::strong_count(base.0.as_ref().unwrap()), 1);
+
+        // Dropping the last owner frees every node
+        drop(base);
+        assert_eq!(Arc::strong_count(&token), 1);
+    }
+
+    #[test]
+    fn map_shared() {
+        let list = List::new().push(1).push(2).push(3);
+
+        let mapped = list.map_shared(|x| if *x == 3 { Some(30) } else { None });
+        assert_eq!(mapped.iter().copied().collect::<Vec<_>>(), vec![30, 2, 1]);
+
+        // Only the replaced head was rebuilt, the rest is shared
+        let tail = list.tail();
+        assert_eq!(Arc::strong_count(tail.0.as_ref().unwrap()), 3);
+
+        let mapped = list.map_shared(|x| if *x == 1 { Some(10) } else { None });
+        assert_eq!(mapped.iter().copied().collect::<Vec<_>>(), vec![3, 2, 10]);
+        assert_eq!(Arc::strong_count(list.0.as_ref().unwrap()), 1);
+    }
+
+    #[test]
+    fn map_shared_unchanged() {
+        let list = List::new().push(1).push(2).push(3);
+
+        let mapped = list.map_shared(|_| None);
+        assert_eq!(mapped.iter().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+        assert_eq!(Arc::strong_count(list.0.as_ref().unwrap()), 2);
+        assert!(Arc::ptr_eq(
+            list.0.as_ref().unwrap(),
+            mapped.0.as_ref().unwrap()
+        ));
+
+        let empty: List<i32> = List::new();
+        assert_eq!(empty.map_shared(|_| None).head(), None);
+    }
+
+    #[test]
+    fn map() {
+        let list = List::new().push(1).push(2);
+        let mapped = list.map(|x| x * 10);
+
+        let mut iter = mapped.iter();
+        assert_eq!(iter.next(), Some(&20));
+        assert_eq!(iter.next(), Some(&10));
+        assert_eq!(iter.next(), None);
+
+        // Original is untouched
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![2, 1]);
+
+        let mapped = list.map(|x| x.to_string());
+        assert_eq!(mapped.head(), Some(&"2".to_string()));
+    }
+
+    #[test]
+    fn intersperse() {
+        let list = List::new().push(1).push(2).push(3);
+        let interspersed = list.intersperse(0);
+        assert_eq!(
+            interspersed.iter().copied().collect::<Vec<_>>(),
+            vec![3, 0, 2, 0, 1]
+        );
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+
+        let list = List::new().push(1);
+        assert_eq!(
+            list.intersperse(0).iter().copied().collect::<Vec<_>>(),
+            vec![1]
+        );
+
+        let list: List<i32> = List::new();
+        assert_eq!(list.intersperse(0).head(), None);
+    }
+
+    #[test]
+    fn from_iter() {
+        let v = vec![1, 2, 3];
+        let list: List<i32> = v.iter().copied().collect();
+        assert_eq!(list.head(), Some(&1));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), v);
+
+        let list: List<i32> = std::iter::empty().collect();
+        assert_eq!(list.head(), None);
+    }
+
+    #[test]
+    fn into_iter() {
+        let list: List<i32> = vec![1, 2, 3].into_iter().collect();
+        let mut values = Vec::new();
+        for x in list {
+            values.push(x);
+        }
+        assert_eq!(values, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn into_iter_shared() {
+        let token = Arc::new(());
+        let base = List::new().push(token.clone()).push(token.clone());
+        let list = base.push(token.clone());
+        assert_eq!(Arc::strong_count(&token), 4);
+
+        // Unique head is moved out, shared nodes are cloned
+        let values: Vec<_> = list.into_iter().collect();
+        assert_eq!(values.len(), 3);
+        assert_eq!(Arc::strong_count(&token), 6);
+        assert_eq!(base.iter().count(), 2);
+
+        drop(values);
+        drop(base);
+        assert_eq!(Arc::strong_count(&token), 1);
+    }
+
+    #[test]
+    fn last() {
+        let list: List<i32> = List::new();
+        assert_eq!(list.last(), None);
+
+        let list = list.push(1);
+        assert_eq!(list.last(), Some(&1));
+
+        let list = list.push(2).push(3);
+        assert_eq!(list.last(), Some(&1));
+        assert_eq!(list.head(), Some(&3));
+    }
+
+    #[test]
+    fn to_mut_vec() {
+        let base = List::new().push(1).push(2);
+        let list = base.push(3);
+
+        let mut values = list.to_mut_vec();
+        assert_eq!(values, vec![3, 2, 1]);
+        for x in values.iter_mut() {
+            *x *= 10;
+        }
+        assert_eq!(values, vec![30, 20, 10]);
+
+        // Neither the list nor the shared tail see the mutation
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+        assert_eq!(base.iter().copied().collect::<Vec<_>>(), vec![2, 1]);
+
+        // The copy can be collected back into a new list
+        let edited: List<i32> = values.into_iter().collect();
+        assert_eq!(edited.head(), Some(&30));
+        assert_eq!(list.head(), Some(&3));
+    }
+
+    #[test]
+    fn windows() {
+        let list: List<i32> = vec![1, 2, 3].into_iter().collect();
+
+        let windows: Vec<_> = list.windows(1).collect();
+        assert_eq!(windows, vec![vec![&1], vec![&2], vec![&3]]);
+
+        let windows: Vec<_> = list.windows(2).collect();
+        assert_eq!(windows, vec![vec![&1, &2], vec![&2, &3]]);
+
+        let windows: Vec<_> = list.windows(3).collect();
+        assert_eq!(windows, vec![vec![&1, &2, &3]]);
+
+        assert_eq!(list.windows(4).count(), 0);
+        assert_eq!(List::<i32>::new().windows(1).count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn windows_zero() {
+        let list = List::new().push(1);
+        let _ = list.windows(0);
+    }
+
+    #[test]
+    fn drop_while() {
+        let list = List::new().push(1).push(2).push(3).push(4);
+
+        let rest = list.drop_while(|x| *x > 2);
+        assert_eq!(rest.iter().collect::<Vec<_>>(), vec![&2, &1]);
+        assert!(Arc::ptr_eq(
+            rest.0.as_ref().unwrap(),
+            list.tail().tail().0.as_ref().unwrap()
+        ));
+
+        let rest = list.drop_while(|_| false);
+        assert!(Arc::ptr_eq(
+            rest.0.as_ref().unwrap(),
+            list.0.as_ref().unwrap()
+        ));
+
+        assert_eq!(list.drop_while(|_| true).head(), None);
+        assert_eq!(List::<i32>::new().drop_while(|_| true).head(), None);
+    }
+
+    #[test]
+    fn take_while() {
+        let list = List::new().push(1).push(2).push(3).push(4);
+
+        let prefix = list.take_while(|x| *x > 2);
+        assert_eq!(prefix.iter().collect::<Vec<_>>(), vec![&4, &3]);
+        assert_eq!(Arc::strong_count(list.0.as_ref().unwrap()), 1);
+
+        assert_eq!(list.take_while(|_| false).head(), None);
+        assert_eq!(list.take_while(|_| true).iter().count(), 4);
+        assert_eq!(List::<i32>::new().take_while(|_| true).head(), None);
+    }
+
+    #[test]
+    fn get() {
+        let list = List::new().push(1).push(2).push(3);
+        assert_eq!(list.get(0), Some(&3));
+        assert_eq!(list.get(1), Some(&2));
+        assert_eq!(list.get(2), Some(&1));
+        assert_eq!(list.get(3), None);
+        assert_eq!(List::<i32>::new().get(0), None);
+    }
+
+    #[test]
+    fn iter_clone() {
+        let list = List::new().push(1).push(2).push(3);
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&3));
+
+        let mut clone = iter.clone();
+        assert_eq!(clone.next(), Some(&2));
+        assert_eq!(clone.next(), Some(&1));
+        assert_eq!(clone.next(), None);
+
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn count() {
+        let list = List::new().push(1).push(2).push(3).push(4);
+        assert_eq!(list.count(|x| x % 2 == 0), 2);
+        assert_eq!(list.count(|_| true), 4);
+        assert_eq!(list.count(|_| false), 0);
+        assert_eq!(List::<i32>::new().count(|_| true), 0);
+
+        // Counting a shared tail leaves the original untouched
+        let tail = list.tail();
+        assert_eq!(tail.count(|x| *x < 4), 3);
+        assert_eq!(list.head(), Some(&4));
+    }
+
+    #[test]
+    fn eq_slice() {
+        let list = List::new().push(1).push(2).push(3);
+        assert!(list.eq_slice(&[3, 2, 1]));
+        assert!(list.tail().eq_slice(&[2, 1]));
+
+        assert!(!list.eq_slice(&[1, 2, 3]));
+        assert!(!list.eq_slice(&[3, 2]));
+        assert!(!list.eq_slice(&[3, 2, 1, 0]));
+
+        assert!(List::<i32>::new().eq_slice(&[]));
+        assert!(!List::new().eq_slice(&[1]));
+    }
+
+    #[test]
+    fn append() {
+        let a = List::new().push(1).push(2);
+        let b = List::new().push(3).push(4);
+
+        let list = a.append(&b);
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec![&2, &1, &4, &3]);
+
+        // `b` is shared as the tail, `a` is left untouched
+        assert!(Arc::ptr_eq(
+            list.tail().tail().0.as_ref().unwrap(),
+            b.0.as_ref().unwrap()
+        ));
+        assert_eq!(Arc::strong_count(a.0.as_ref().unwrap()), 1);
+        assert_eq!(a.iter().collect::<Vec<_>>(), vec![&2, &1]);
+    }
+
+    #[test]
+    fn append_empty() {
+        let list = List::new().push(1).push(2);
+        let empty = List::new();
+
+        let appended = empty.append(&list);
+        assert_eq!(appended.iter().collect::<Vec<_>>(), vec![&2, &1]);
+        assert!(Arc::ptr_eq(
+            appended.0.as_ref().unwrap(),
+            list.0.as_ref().unwrap()
+        ));
+
+        let appended = list.append(&empty);
+        assert_eq!(appended.iter().collect::<Vec<_>>(), vec![&2, &1]);
+
+        assert_eq!(empty.append(&List::new()).head(), None);
+    }
+
+    #[test]
+    fn enumerate() {
+        let list = List::new().push('c').push('b').push('a');
+        let pairs: Vec<_> = list.enumerate().collect();
+        assert_eq!(pairs, vec![(0, &'a'), (1, &'b'), (2, &'c')]);
+
+        let tail = list.tail();
+        let pairs: Vec<_> = tail.enumerate().collect();
+        assert_eq!(pairs, vec![(0, &'b'), (1, &'c')]);
+    }
 }
@@ -9,6 +9,15 @@
 //! linked_lists_rs = "1"
 //! ```
 //!
+//! # Features
+//! - `std` (enabled by default): enables the [`concurrent`] module and the helpers relying on `std`
+//!   such as hashing, without it the crate is `no_std` and only requires `alloc`
+//!
+//! ```toml
+//! [dependencies]
+//! linked_lists_rs = { version = "1", default-features = false }
+//! ```
+//!
 //! # Example
 //! ```
 //! use linked_lists_rs::stack::Stack;
@@ -22,9 +31,23 @@
 //! assert_eq!(None, stack.pop());
 //! ```
 
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
+
+#[cfg(test)]
+mod counting_allocator;
+
+/// Concurrent Queue implementation
+#[cfg(feature = "std")]
+pub mod concurrent;
 /// Immutable List implementation
 pub mod immutable_list;
+/// Doubly Linked List implementation
+pub mod list;
 /// Queue implementation
 pub mod queue;
+/// Ring Queue implementation
+pub mod ring_queue;
 /// Stack implementation
 pub mod stack;
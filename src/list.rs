@@ -0,0 +1,1105 @@
+//! A [`LinkedList`] is a linear structure where elements can be added and removed
+//! at both ends, the front and the back.
+//!
+//! It can be used as a Stack, a Queue or both at the same time (a Deque)
+//! and can be iterated in both directions.
+//!
+//! This [`LinkedList`] implementation uses the doubly linked list concept,
+//! each node points to both its previous and next nodes.
+//! This implementation uses raw pointers and the unsafe keyword
+//! this is so to preserve performance and aims to be a 100% safe abstraction
+//!
+
+use alloc::boxed::Box;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ptr::null_mut;
+
+type Link<T> = *mut Node<T>;
+
+/// LinkedList Struct
+pub struct LinkedList<T> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    // The list owns its nodes, which matters to the drop check
+    _marker: PhantomData<Box<Node<T>>>,
+}
+
+struct Node<T> {
+    value: T,
+    prev: Link<T>,
+    next: Link<T>,
+}
+
+impl<T> LinkedList<T> {
+    /// Creates a new [`LinkedList`]
+    ///
+    /// # Example
+    /// Creating a new [`LinkedList`] of `i32`
+    /// ```
+    /// use linked_lists_rs::list::LinkedList;
+    /// let list: LinkedList<i32> = LinkedList::new();
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            head: null_mut(),
+            tail: null_mut(),
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Return the number of elements in the [`LinkedList`]
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::list::LinkedList;
+    /// let mut list = LinkedList::new();
+    ///
+    /// list.push_back(5);
+    ///
+    /// assert_eq!(1, list.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return `true` if the [`LinkedList`] contains no elements
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::list::LinkedList;
+    /// let mut list = LinkedList::new();
+    /// assert!(list.is_empty());
+    ///
+    /// list.push_back(5);
+    /// assert!(!list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Push a new value on the front of the [`LinkedList`]
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::list::LinkedList;
+    /// let mut list = LinkedList::new();
+    ///
+    /// list.push_front(5);
+    /// list.push_front(6);
+    ///
+    /// assert_eq!(Some(6), list.pop_front());
+    /// ```
+    pub fn push_front(&mut self, value: T) {
+        let node = Box::into_raw(Box::new(Node {
+            value,
+            prev: null_mut(),
+            next: self.head,
+        }));
+        if self.head.is_null() {
+            self.tail = node;
+        } else {
+            unsafe {
+                (*self.head).prev = node;
+            }
+        }
+        self.head = node;
+        self.len += 1;
+    }
+
+    /// Push a new value on the back of the [`LinkedList`]
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::list::LinkedList;
+    /// let mut list = LinkedList::new();
+    ///
+    /// list.push_back(5);
+    /// list.push_back(6);
+    ///
+    /// assert_eq!(Some(6), list.pop_back());
+    /// ```
+    pub fn push_back(&mut self, value: T) {
+        let node = Box::into_raw(Box::new(Node {
+            value,
+            prev: self.tail,
+            next: null_mut(),
+        }));
+        if self.tail.is_null() {
+            self.head = node;
+        } else {
+            unsafe {
+                (*self.tail).next = node;
+            }
+        }
+        self.tail = node;
+        self.len += 1;
+    }
+
+    /// Pops and return the value on the front of the [`LinkedList`]
+    /// Returns `None` if the [`LinkedList`] is empty
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::list::LinkedList;
+    /// let mut list = LinkedList::new();
+    ///
+    /// list.push_back(5);
+    ///
+    /// assert_eq!(Some(5), list.pop_front());
+    /// assert_eq!(None, list.pop_front());
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.head.is_null() {
+            return None;
+        }
+        unsafe {
+            let node = Box::from_raw(self.head);
+            self.head = node.next;
+            if self.head.is_null() {
+                self.tail = null_mut();
+            } else {
+                (*self.head).prev = null_mut();
+            }
+            self.len -= 1;
+            Some(node.value)
+        }
+    }
+
+    /// Pops and return the value on the back of the [`LinkedList`]
+    /// Returns `None` if the [`LinkedList`] is empty
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::list::LinkedList;
+    /// let mut list = LinkedList::new();
+    ///
+    /// list.push_front(5);
+    ///
+    /// assert_eq!(Some(5), list.pop_back());
+    /// assert_eq!(None, list.pop_back());
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.tail.is_null() {
+            return None;
+        }
+        unsafe {
+            let node = Box::from_raw(self.tail);
+            self.tail = node.prev;
+            if self.tail.is_null() {
+                self.head = null_mut();
+            } else {
+                (*self.tail).next = null_mut();
+            }
+            self.len -= 1;
+            Some(node.value)
+        }
+    }
+
+    /// Return a reference to the value on the front of the [`LinkedList`]
+    /// Returns `None` if the [`LinkedList`] is empty
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::list::LinkedList;
+    /// let mut list = LinkedList::new();
+    ///
+    /// list.push_back(5);
+    /// list.push_back(6);
+    ///
+    /// assert_eq!(Some(&5), list.front());
+    /// ```
+    pub fn front(&self) -> Option<&T> {
+        unsafe { self.head.as_ref().map(|node| &node.value) }
+    }
+
+    /// Return a mutable reference to the value on the front of the [`LinkedList`]
+    /// Returns `None` if the [`LinkedList`] is empty
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::list::LinkedList;
+    /// let mut list = LinkedList::new();
+    ///
+    /// list.push_back(5);
+    ///
+    /// if let Some(front) = list.front_mut() {
+    ///     *front *= 5;
+    /// }
+    /// assert_eq!(Some(25), list.pop_front());
+    /// ```
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        unsafe { self.head.as_mut().map(|node| &mut node.value) }
+    }
+
+    /// Return a reference to the value on the back of the [`LinkedList`]
+    /// Returns `None` if the [`LinkedList`] is empty
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::list::LinkedList;
+    /// let mut list = LinkedList::new();
+    ///
+    /// list.push_back(5);
+    /// list.push_back(6);
+    ///
+    /// assert_eq!(Some(&6), list.back());
+    /// ```
+    pub fn back(&self) -> Option<&T> {
+        unsafe { self.tail.as_ref().map(|node| &node.value) }
+    }
+
+    /// Return a mutable reference to the value on the back of the [`LinkedList`]
+    /// Returns `None` if the [`LinkedList`] is empty
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::list::LinkedList;
+    /// let mut list = LinkedList::new();
+    ///
+    /// list.push_back(5);
+    ///
+    /// if let Some(back) = list.back_mut() {
+    ///     *back *= 5;
+    /// }
+    /// assert_eq!(Some(25), list.pop_back());
+    /// ```
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        unsafe { self.tail.as_mut().map(|node| &mut node.value) }
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Lists the values from front to back instead of the raw node pointers.
+impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("LinkedList ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// The list uniquely owns its nodes, so it is as thread safe as `T` itself.
+unsafe impl<T: Send> Send for LinkedList<T> {}
+unsafe impl<T: Sync> Sync for LinkedList<T> {}
+
+impl<T, const N: usize> From<[T; N]> for LinkedList<T> {
+    fn from(values: [T; N]) -> Self {
+        let mut list = Self::new();
+        for value in values {
+            list.push_back(value);
+        }
+        list
+    }
+}
+
+// Custom code within the destructor.
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+/// [`IntoIter`] struct for [`LinkedList`] consumed iteration
+/// Iterate from front to back, or from back to front with [`Iterator::rev`]
+pub struct IntoIter<T>(LinkedList<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+
+    fn count(self) -> usize {
+        self.0.len
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back()
+    }
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Iterator to the [`LinkedList`]
+    /// Consumes the data structure on iteration
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    ///
+    /// // Iterate the list backwards and verify its values
+    /// for (i, x) in std::iter::zip(list.into_iter().rev(), [3, 2, 1]) {
+    ///     assert_eq!(i, x);
+    /// }
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+/// [`Iter`] struct for [`LinkedList`] referenced iteration
+/// Iterate from front to back, or from back to front with [`Iterator::rev`]
+pub struct Iter<'a, T> {
+    front: Link<T>,
+    back: Link<T>,
+    len: usize,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        unsafe {
+            let node = &*self.front;
+            self.front = node.next;
+            self.len -= 1;
+            Some(&node.value)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn count(self) -> usize {
+        self.len
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        unsafe {
+            let node = &*self.back;
+            self.back = node.prev;
+            self.len -= 1;
+            Some(&node.value)
+        }
+    }
+}
+
+// Manual impl as deriving would needlessly require `T: Clone`.
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter {
+            front: self.front,
+            back: self.back,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> LinkedList<T> {
+    /// Reference Iterator to the [`LinkedList`]
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    ///
+    /// // Use iter to iterate the list in both directions
+    /// assert_eq!(vec![&1, &2, &3], list.iter().collect::<Vec<_>>());
+    /// assert_eq!(vec![&3, &2, &1], list.iter().rev().collect::<Vec<_>>());
+    ///
+    /// // List is not consumed
+    /// assert_eq!(Some(&1), list.front());
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            front: self.head,
+            back: self.tail,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// [`IterMut`] struct for [`LinkedList`] mutable referenced iteration
+/// Iterate from front to back, or from back to front with [`Iterator::rev`]
+pub struct IterMut<'a, T> {
+    front: Link<T>,
+    back: Link<T>,
+    len: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        unsafe {
+            let node = &mut *self.front;
+            self.front = node.next;
+            self.len -= 1;
+            Some(&mut node.value)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn count(self) -> usize {
+        self.len
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        unsafe {
+            let node = &mut *self.back;
+            self.back = node.prev;
+            self.len -= 1;
+            Some(&mut node.value)
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> LinkedList<T> {
+    /// Mutable Reference Iterator to the [`LinkedList`]
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    ///
+    /// // Use iter_mut to iterate the list and mutate it's values
+    /// for i in &mut list {
+    ///     *i *= 2;
+    /// }
+    ///
+    /// // Assert values mutate as expected
+    /// for x in [6, 4, 2] {
+    ///     assert_eq!(Some(x), list.pop_back());
+    /// }
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            front: self.head,
+            back: self.tail,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// [`CursorMut`] struct for [`LinkedList`] in-place editing
+/// Points at an element, or at the "ghost" position between the back and the front
+pub struct CursorMut<'a, T> {
+    current: Link<T>,
+    list: &'a mut LinkedList<T>,
+}
+
+impl<T> CursorMut<'_, T> {
+    /// Moves the cursor to the next element
+    /// From the back it moves to the ghost position, and from the ghost position to the front
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2]);
+    /// let mut cursor = list.cursor_mut();
+    ///
+    /// cursor.move_next();
+    /// assert_eq!(Some(&mut 2), cursor.current());
+    ///
+    /// cursor.move_next();
+    /// assert_eq!(None, cursor.current());
+    ///
+    /// cursor.move_next();
+    /// assert_eq!(Some(&mut 1), cursor.current());
+    /// ```
+    pub fn move_next(&mut self) {
+        self.current = if self.current.is_null() {
+            self.list.head
+        } else {
+            unsafe { (*self.current).next }
+        };
+    }
+
+    /// Moves the cursor to the previous element
+    /// From the front it moves to the ghost position, and from the ghost position to the back
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2]);
+    /// let mut cursor = list.cursor_mut();
+    ///
+    /// cursor.move_prev();
+    /// assert_eq!(None, cursor.current());
+    ///
+    /// cursor.move_prev();
+    /// assert_eq!(Some(&mut 2), cursor.current());
+    /// ```
+    pub fn move_prev(&mut self) {
+        self.current = if self.current.is_null() {
+            self.list.tail
+        } else {
+            unsafe { (*self.current).prev }
+        };
+    }
+
+    /// Return a mutable reference to the element the cursor points at
+    /// Returns `None` at the ghost position
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2]);
+    /// let mut cursor = list.cursor_mut();
+    ///
+    /// if let Some(value) = cursor.current() {
+    ///     *value = 10;
+    /// }
+    ///
+    /// assert_eq!(Some(&10), list.front());
+    /// ```
+    pub fn current(&mut self) -> Option<&mut T> {
+        unsafe { self.current.as_mut().map(|node| &mut node.value) }
+    }
+
+    /// Inserts a new value before the element the cursor points at
+    /// At the ghost position the value is pushed on the back of the [`LinkedList`]
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::list::LinkedList;
+    /// let mut list = LinkedList::from([1, 3]);
+    /// let mut cursor = list.cursor_mut();
+    ///
+    /// cursor.move_next();
+    /// cursor.insert_before(2);
+    ///
+    /// assert_eq!(Some(&mut 3), cursor.current());
+    /// assert_eq!(vec![1, 2, 3], list.into_iter().collect::<Vec<_>>());
+    /// ```
+    pub fn insert_before(&mut self, value: T) {
+        let prev = if self.current.is_null() {
+            self.list.tail
+        } else {
+            unsafe { (*self.current).prev }
+        };
+        unsafe {
+            self.list.link_between(value, prev, self.current);
+        }
+    }
+
+    /// Inserts a new value after the element the cursor points at
+    /// At the ghost position the value is pushed on the front of the [`LinkedList`]
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::list::LinkedList;
+    /// let mut list = LinkedList::from([1, 3]);
+    /// let mut cursor = list.cursor_mut();
+    ///
+    /// cursor.insert_after(2);
+    ///
+    /// assert_eq!(Some(&mut 1), cursor.current());
+    /// assert_eq!(vec![1, 2, 3], list.into_iter().collect::<Vec<_>>());
+    /// ```
+    pub fn insert_after(&mut self, value: T) {
+        let next = if self.current.is_null() {
+            self.list.head
+        } else {
+            unsafe { (*self.current).next }
+        };
+        unsafe {
+            self.list.link_between(value, self.current, next);
+        }
+    }
+
+    /// Removes and return the element the cursor points at, moving the cursor to the next one
+    /// Returns `None` at the ghost position
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// let mut cursor = list.cursor_mut();
+    ///
+    /// cursor.move_next();
+    ///
+    /// assert_eq!(Some(2), cursor.remove_current());
+    /// assert_eq!(Some(&mut 3), cursor.current());
+    /// assert_eq!(vec![1, 3], list.into_iter().collect::<Vec<_>>());
+    /// ```
+    pub fn remove_current(&mut self) -> Option<T> {
+        if self.current.is_null() {
+            return None;
+        }
+        unsafe {
+            let node = self.list.unlink(self.current);
+            self.current = node.next;
+            Some(node.value)
+        }
+    }
+}
+
+impl<T> LinkedList<T> {
+    /// Cursor to the [`LinkedList`] pointing at the front element
+    /// Allows moving in both directions and inserting or removing elements in place
+    /// On an empty [`LinkedList`] the cursor starts at the ghost position
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// let mut cursor = list.cursor_mut();
+    ///
+    /// // Double every odd value and drop the even ones
+    /// while let Some(value) = cursor.current() {
+    ///     if *value % 2 == 0 {
+    ///         cursor.remove_current();
+    ///     } else {
+    ///         *value *= 2;
+    ///         cursor.move_next();
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(vec![2, 6], list.into_iter().collect::<Vec<_>>());
+    /// ```
+    pub fn cursor_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.head,
+            list: self,
+        }
+    }
+
+    // Links a new node holding `value` between `prev` and `next`, which must be adjacent.
+    // A null `prev` or `next` stands for the front or the back of the list.
+    unsafe fn link_between(&mut self, value: T, prev: Link<T>, next: Link<T>) {
+        let node = Box::into_raw(Box::new(Node { value, prev, next }));
+        if prev.is_null() {
+            self.head = node;
+        } else {
+            (*prev).next = node;
+        }
+        if next.is_null() {
+            self.tail = node;
+        } else {
+            (*next).prev = node;
+        }
+        self.len += 1;
+    }
+
+    // Detaches `node` from the list, which must own it, and hands its box back.
+    unsafe fn unlink(&mut self, node: Link<T>) -> Box<Node<T>> {
+        let node = Box::from_raw(node);
+        if node.prev.is_null() {
+            self.head = node.next;
+        } else {
+            (*node.prev).next = node.next;
+        }
+        if node.next.is_null() {
+            self.tail = node.prev;
+        } else {
+            (*node.next).prev = node.prev;
+        }
+        self.len -= 1;
+        node
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::LinkedList;
+
+    #[test]
+    fn basics_front() {
+        let mut list = LinkedList::new();
+
+        // Check empty list behaves right
+        assert_eq!(list.pop_front(), None);
+
+        // Populate list
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
+
+        // Check normal removal
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.pop_front(), Some(2));
+
+        // Push some more just to make sure nothing's corrupted
+        list.push_front(4);
+        list.push_front(5);
+
+        // Check normal removal
+        assert_eq!(list.pop_front(), Some(5));
+        assert_eq!(list.pop_front(), Some(4));
+
+        // Check exhaustion
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.pop_back(), None);
+    }
+
+    #[test]
+    fn basics_back() {
+        let mut list = LinkedList::new();
+
+        // Check empty list behaves right
+        assert_eq!(list.pop_back(), None);
+
+        // Populate list
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        // Check normal removal
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_back(), Some(2));
+
+        // Push some more just to make sure nothing's corrupted
+        list.push_back(4);
+        list.push_back(5);
+
+        // Check normal removal
+        assert_eq!(list.pop_back(), Some(5));
+        assert_eq!(list.pop_back(), Some(4));
+
+        // Check exhaustion
+        assert_eq!(list.pop_back(), Some(1));
+        assert_eq!(list.pop_back(), None);
+        assert_eq!(list.pop_front(), None);
+    }
+
+    #[test]
+    fn mixed_ends() {
+        let mut list = LinkedList::new();
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+        assert_eq!(list.len(), 3);
+
+        // Pop the last element from the opposite end it was pushed
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_back(), Some(2));
+        assert!(list.is_empty());
+
+        // Make sure both ends are reset once empty
+        list.push_front(4);
+        assert_eq!(list.front(), Some(&4));
+        assert_eq!(list.back(), Some(&4));
+        assert_eq!(list.pop_back(), Some(4));
+        list.push_back(5);
+        assert_eq!(list.pop_front(), Some(5));
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn peek() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+        assert_eq!(list.front_mut(), None);
+        assert_eq!(list.back_mut(), None);
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&3));
+        if let Some(value) = list.front_mut() {
+            *value = 10;
+        }
+        if let Some(value) = list.back_mut() {
+            *value = 30;
+        }
+
+        assert_eq!(list.pop_front(), Some(10));
+        assert_eq!(list.pop_back(), Some(30));
+        assert_eq!(list.front(), Some(&2));
+        assert_eq!(list.back(), Some(&2));
+    }
+
+    #[test]
+    fn len() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+
+        list.push_back(1);
+        list.push_front(2);
+        assert_eq!(list.len(), 2);
+
+        list.pop_back();
+        assert_eq!(list.len(), 1);
+        list.pop_front();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn into_iter() {
+        let list = LinkedList::from([1, 2, 3]);
+        let mut iter = list.into_iter();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn iter() {
+        let list = LinkedList::from([1, 2, 3]);
+        let mut iter = list.iter();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+
+        let backwards: Vec<_> = list.iter().rev().collect();
+        assert_eq!(backwards, vec![&3, &2, &1]);
+    }
+
+    #[test]
+    fn iter_both_ends_meet() {
+        let list = LinkedList::from([1, 2, 3, 4]);
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.next_back(), Some(&3));
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn iter_clone() {
+        let list = LinkedList::from([1, 2, 3]);
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+
+        let mut clone = iter.clone();
+        assert_eq!(clone.next_back(), Some(&3));
+        assert_eq!(clone.next(), Some(&2));
+        assert_eq!(clone.next(), None);
+
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut list = LinkedList::from([1, 2, 3]);
+        let mut iter = list.iter_mut();
+        assert_eq!(iter.next(), Some(&mut 1));
+        if let Some(value) = iter.next_back() {
+            *value = 30;
+        }
+        assert_eq!(iter.next(), Some(&mut 2));
+        assert_eq!(iter.next_back(), None);
+
+        for value in list.iter_mut().rev() {
+            *value += 1;
+        }
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![2, 3, 31]);
+    }
+
+    #[test]
+    fn debug() {
+        let list = LinkedList::from([1, 2, 3]);
+        assert_eq!(format!("{list:?}"), "LinkedList [1, 2, 3]");
+    }
+
+    #[test]
+    fn drops_remaining_values() {
+        use std::rc::Rc;
+
+        let value = Rc::new(());
+        let mut list = LinkedList::new();
+        for _ in 0..3 {
+            list.push_back(Rc::clone(&value));
+            list.push_front(Rc::clone(&value));
+        }
+        assert_eq!(Rc::strong_count(&value), 7);
+
+        drop(list.pop_back());
+        assert_eq!(Rc::strong_count(&value), 6);
+
+        drop(list);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn long_list() {
+        let mut list = LinkedList::new();
+        for x in 0..100_000 {
+            list.push_back(x);
+        }
+        assert_eq!(list.len(), 100_000);
+    }
+
+    #[test]
+    fn send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<LinkedList<i32>>();
+    }
+
+    #[test]
+    fn cursor_ghost() {
+        let mut list = LinkedList::new();
+        let mut cursor = list.cursor_mut();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.remove_current(), None);
+        cursor.move_next();
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+
+        // Inserting around the ghost lands at the ends
+        cursor.insert_before(2);
+        cursor.insert_after(1);
+        cursor.insert_before(3);
+        assert_eq!(cursor.current(), None);
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 1));
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&mut 3));
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&3));
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn cursor_insert_middle() {
+        let mut list = LinkedList::from([1, 4]);
+        let mut cursor = list.cursor_mut();
+        cursor.insert_after(2);
+        cursor.move_next();
+        cursor.insert_after(3);
+        assert_eq!(cursor.current(), Some(&mut 2));
+        cursor.move_next();
+        cursor.move_next();
+        cursor.insert_before(35);
+        assert_eq!(cursor.current(), Some(&mut 4));
+
+        assert_eq!(list.len(), 5);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 35, 4]
+        );
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![4, 35, 3, 2, 1]
+        );
+    }
+
+    #[test]
+    fn cursor_remove_head_and_tail() {
+        let mut list = LinkedList::from([1, 2, 3]);
+
+        // Removing the head moves on to the new head
+        let mut cursor = list.cursor_mut();
+        assert_eq!(cursor.remove_current(), Some(1));
+        assert_eq!(cursor.current(), Some(&mut 2));
+        assert_eq!(list.front(), Some(&2));
+        assert_eq!(list.len(), 2);
+
+        // Removing the tail moves on to the ghost position
+        let mut cursor = list.cursor_mut();
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.remove_current(), Some(3));
+        assert_eq!(cursor.current(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&mut 2));
+        assert_eq!(list.back(), Some(&2));
+
+        // Removing the only element leaves both ends reset
+        let mut cursor = list.cursor_mut();
+        assert_eq!(cursor.remove_current(), Some(2));
+        assert_eq!(cursor.current(), None);
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+
+        list.push_back(4);
+        list.push_front(5);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![5, 4]);
+    }
+
+    #[test]
+    fn count() {
+        let mut list = LinkedList::from([1, 2, 3, 4]);
+        assert_eq!(list.iter().count(), list.len());
+        assert_eq!(list.iter_mut().count(), 4);
+
+        let mut iter = list.iter();
+        iter.next();
+        assert_eq!(iter.count(), 3);
+
+        let mut iter = list.iter_mut();
+        iter.next();
+        iter.next();
+        assert_eq!(iter.count(), 2);
+
+        let mut iter = list.into_iter();
+        iter.next();
+        assert_eq!(iter.count(), 3);
+    }
+}
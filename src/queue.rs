@@ -8,16 +8,33 @@
 //! This implementation uses raw pointers and the unsafe keyword
 //! this is so to preserve performance and aims to be a 100% safe abstraction
 //!
+//! A [`Queue`] created with [`Queue::with_recycling`] keeps popped node allocations
+//! in a pool and reuses them on push, cutting allocator traffic under heavy churn.
+//!
 
-use std::ptr::null_mut;
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::mem::{self, MaybeUninit};
+use core::ptr::{self, null_mut};
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+#[cfg(feature = "std")]
+use std::hash::Hash;
 
 type Link<T> = *mut Node<T>;
 
 /// Queue Struct
-#[derive(Debug)]
 pub struct Queue<T> {
     head: Link<T>,
     last: *mut Node<T>,
+    len: usize,
+    capacity: Option<usize>,
+    pool: Vec<Box<MaybeUninit<Node<T>>>>,
+    max_pooled: usize,
 }
 
 #[derive(Debug)]
@@ -44,15 +61,107 @@ impl<T> Queue<T> {
     /// use linked_lists_rs::queue::Queue;
     /// let queue: Queue<i32> = Queue::new();
     /// ```
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self {
             head: null_mut(),
             last: null_mut(),
+            len: 0,
+            capacity: None,
+            pool: Vec::new(),
+            max_pooled: 0,
         }
     }
 
+    /// Creates a new bounded [`Queue`] that holds at most `capacity` elements
+    ///
+    /// # Example
+    /// ```
+    /// use linked_lists_rs::queue::Queue;
+    /// let mut queue = Queue::with_capacity(1);
+    ///
+    /// assert_eq!(Ok(()), queue.try_push(1));
+    /// assert_eq!(Err(2), queue.try_push(2));
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut queue = Self::new();
+        queue.capacity = Some(capacity);
+        queue
+    }
+
+    /// Creates a new [`Queue`] that keeps up to `max_pooled` popped nodes for reuse
+    /// Pushing reuses a pooled node when available instead of allocating a new one
+    /// Room for the pool itself is reserved upfront
+    ///
+    /// # Example
+    /// ```
+    /// use linked_lists_rs::queue::Queue;
+    /// let mut queue = Queue::with_recycling(16);
+    ///
+    /// queue.push(1);
+    /// assert_eq!(Some(1), queue.pop());
+    ///
+    /// // Reuses the node freed by the previous pop
+    /// queue.push(2);
+    /// assert_eq!(Some(2), queue.pop());
+    /// ```
+    pub fn with_recycling(max_pooled: usize) -> Self {
+        let mut queue = Self::new();
+        queue.pool = Vec::with_capacity(max_pooled);
+        queue.max_pooled = max_pooled;
+        queue
+    }
+
+    /// Return the maximum number of elements of the [`Queue`]
+    /// Returns `None` if the [`Queue`] is unbounded
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let queue: Queue<i32> = Queue::with_capacity(3);
+    ///
+    /// assert_eq!(Some(3), queue.capacity());
+    /// assert_eq!(None, Queue::<i32>::new().capacity());
+    /// ```
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    /// Return the number of elements in the [`Queue`]
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let mut queue = Queue::new();
+    ///
+    /// queue.push(5);
+    ///
+    /// assert_eq!(1, queue.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return `true` if the [`Queue`] contains no elements
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let mut queue = Queue::new();
+    /// assert!(queue.is_empty());
+    ///
+    /// queue.push(5);
+    /// assert!(!queue.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
     /// Push a new value on the end of the [`Queue`]
     ///
+    /// # Panics
+    /// Panics if the [`Queue`] is bounded and already at capacity,
+    /// use [`Queue::try_push`] to handle that case
+    ///
     /// # Example
     /// ```
     /// # use linked_lists_rs::queue::Queue;
@@ -63,8 +172,31 @@ impl<T> Queue<T> {
     /// assert_eq!(Some(5), queue.pop());
     /// ```
     pub fn push(&mut self, value: T) {
-        let new_last = Box::new(Node::new(value));
-        let new_last_prt: *mut _ = Box::into_raw(new_last);
+        if self.try_push(value).is_err() {
+            panic!("push on a full queue");
+        }
+    }
+
+    /// Push a new value on the end of the [`Queue`]
+    /// Returns the value back as `Err` if the [`Queue`] is at capacity
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let mut queue = Queue::with_capacity(1);
+    ///
+    /// assert_eq!(Ok(()), queue.try_push(5));
+    /// assert_eq!(Err(6), queue.try_push(6));
+    ///
+    /// assert_eq!(Some(5), queue.pop());
+    /// assert_eq!(Ok(()), queue.try_push(6));
+    /// ```
+    pub fn try_push(&mut self, value: T) -> Result<(), T> {
+        if self.capacity.is_some_and(|capacity| self.len >= capacity) {
+            return Err(value);
+        }
+
+        let new_last_prt = self.alloc_node(value);
         if self.last.is_null() {
             self.head = new_last_prt;
         } else {
@@ -74,6 +206,8 @@ impl<T> Queue<T> {
         };
 
         self.last = new_last_prt;
+        self.len += 1;
+        Ok(())
     }
 
     /// Pops and return the value on the front of the [`Queue`]
@@ -94,12 +228,15 @@ impl<T> Queue<T> {
             None
         } else {
             unsafe {
-                let node = Box::from_raw(self.head);
-                self.head = node.next;
+                let node = self.head;
+                self.head = (*node).next;
                 if self.head.is_null() {
                     self.last = null_mut();
                 }
-                Some(node.value)
+                self.len -= 1;
+                let value = ptr::read(&(*node).value);
+                self.free_node(node);
+                Some(value)
             }
         }
     }
@@ -140,255 +277,2436 @@ impl<T> Queue<T> {
     pub fn peek_mut(&mut self) -> Option<&mut T> {
         unsafe { self.head.as_mut().map(|node| &mut node.value) }
     }
-}
-
-// Custom code within the destructor.
-impl<T> Drop for Queue<T> {
-    fn drop(&mut self) {
-        while let Some(_) = self.pop() {}
-    }
-}
-
-/// [`IntoIter`] struct for [`Queue`] consumed iteration
-/// Iterate from front to end
-pub struct IntoIter<T>(Queue<T>);
-
-impl<T> Iterator for IntoIter<T> {
-    type Item = T;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.0.pop()
+    /// Return a reference to the value on the back of the [`Queue`]
+    /// Returns `None` if the [`Queue`] is empty
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let mut queue = Queue::new();
+    ///
+    /// queue.push(5);
+    /// queue.push(6);
+    ///
+    /// assert_eq!(Some(&6), queue.peek_back());
+    /// ```
+    pub fn peek_back(&self) -> Option<&T> {
+        unsafe { self.last.as_ref().map(|node| &node.value) }
     }
-}
-
-impl<T> IntoIterator for Queue<T> {
-    type Item = T;
-    type IntoIter = IntoIter<T>;
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.into_iter()
+    /// Return `true` if `other` is a prefix of the [`Queue`] comparing from the front
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let mut queue = Queue::new();
+    /// let mut prefix = Queue::new();
+    ///
+    /// for x in [1, 2, 3] {
+    ///     queue.push(x);
+    /// }
+    /// prefix.push(1);
+    ///
+    /// assert!(queue.starts_with(&prefix));
+    /// ```
+    pub fn starts_with(&self, other: &Queue<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        other.len <= self.len && self.iter().zip(other).all(|(a, b)| a == b)
     }
-}
 
-impl<T> Queue<T> {
-    /// Iterator to the [`Queue`]
-    /// Consumes the data structure on iteration
+    /// Return `true` if `other` is a suffix of the [`Queue`] comparing up to the back
     ///
     /// # Example
     /// ```
     /// # use linked_lists_rs::queue::Queue;
     /// let mut queue = Queue::new();
+    /// let mut suffix = Queue::new();
     ///
-    /// // Insert values into the queue
     /// for x in [1, 2, 3] {
     ///     queue.push(x);
     /// }
+    /// suffix.push(3);
     ///
-    /// // Iterate the queue and verify its values
-    /// for (i, x) in std::iter::zip(queue, [1, 2, 3]) {
-    ///     assert_eq!(i, x);
-    /// }
+    /// assert!(queue.ends_with(&suffix));
     /// ```
-    pub fn into_iter(self) -> IntoIter<T> {
-        IntoIter(self)
+    pub fn ends_with(&self, other: &Queue<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        other.len <= self.len
+            && self
+                .iter()
+                .skip(self.len - other.len)
+                .zip(other)
+                .all(|(a, b)| a == b)
     }
-}
-
-/// [`Iter`] struct for [`Queue`] referenced iteration
-/// Iterate from front to end
-pub struct Iter<'a, T>(Option<&'a Node<T>>);
 
-impl<'a, T> Iterator for Iter<'a, T> {
-    type Item = &'a T;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        self.0.take().map(|node| {
+    /// Retains only the elements specified by the predicate
+    /// Preserves the relative order of the retained elements
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let mut queue = Queue::new();
+    ///
+    /// for x in [1, 2, 3, 4] {
+    ///     queue.push(x);
+    /// }
+    ///
+    /// queue.retain(|x| x % 2 == 0);
+    ///
+    /// assert_eq!(Some(2), queue.pop());
+    /// assert_eq!(Some(4), queue.pop());
+    /// assert_eq!(None, queue.pop());
+    /// ```
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        let mut prev = null_mut();
+        let mut node = self.head;
+        while !node.is_null() {
             unsafe {
-                self.0 = node.next.as_ref();
+                let next = (*node).next;
+                if f(&(*node).value) {
+                    prev = node;
+                } else {
+                    drop(self.unlink(prev, node));
+                }
+                node = next;
             }
-            &node.value
-        })
+        }
     }
-}
-
-impl<'a, T> IntoIterator for &'a Queue<T> {
-    type Item = &'a T;
-    type IntoIter = Iter<'a, T>;
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
+    /// Removes the leading run of elements equal to the front of the [`Queue`], keeping one
+    /// Stops at the first element that differs from the front
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let mut queue = Queue::new();
+    ///
+    /// for x in [1, 1, 1, 2, 1] {
+    ///     queue.push(x);
+    /// }
+    ///
+    /// queue.dedup_front();
+    ///
+    /// assert_eq!(3, queue.len());
+    /// assert_eq!(Some(1), queue.pop());
+    /// assert_eq!(Some(2), queue.pop());
+    /// assert_eq!(Some(1), queue.pop());
+    /// ```
+    pub fn dedup_front(&mut self)
+    where
+        T: PartialEq,
+    {
+        let head = self.head;
+        if head.is_null() {
+            return;
+        }
+        unsafe {
+            while !(*head).next.is_null() && (*(*head).next).value == (*head).value {
+                drop(self.unlink(head, (*head).next));
+            }
+        }
     }
-}
 
-impl<T> Queue<T> {
-    /// Reference Iterator to the [`Queue`]
+    /// Removes up to `max` elements from the front of the [`Queue`] while they satisfy `pred`
+    /// Returns the removed elements as a new [`Queue`] in the same FIFO order,
+    /// bounded by the same capacity
     ///
     /// # Example
     /// ```
     /// # use linked_lists_rs::queue::Queue;
     /// let mut queue = Queue::new();
     ///
-    /// // Insert values into the stack
-    /// for x in [1, 2, 3] {
+    /// for x in [1, 2, 3, 4] {
     ///     queue.push(x);
     /// }
     ///
-    /// // Use iter to iterate the stack and verify its values
-    /// for (i, x) in std::iter::zip(&queue, [1, 2, 3]) {
-    ///     assert_eq!(i, &x);
-    /// }
+    /// let mut taken = queue.take_up_to(2, |x| *x < 4);
     ///
-    /// // Stack is not consumed
-    /// assert_eq!(Some(&1), queue.peek());
+    /// assert_eq!(Some(1), taken.pop());
+    /// assert_eq!(Some(2), taken.pop());
+    /// assert_eq!(None, taken.pop());
+    /// assert_eq!(Some(3), queue.pop());
     /// ```
-    pub fn iter(&self) -> Iter<'_, T> {
-        unsafe { Iter(self.head.as_ref()) }
+    pub fn take_up_to<F: FnMut(&T) -> bool>(&mut self, max: usize, mut pred: F) -> Queue<T> {
+        let mut count = 0;
+        let mut last = null_mut();
+        let mut node = self.head;
+        unsafe {
+            while count < max && !node.is_null() && pred(&(*node).value) {
+                last = node;
+                node = (*node).next;
+                count += 1;
+            }
+            self.split_front(last, count)
+        }
     }
-}
 
-/// [`IterMut`] struct for [`Queue`] mutable referenced iteration
-/// Iterate from front to end
-pub struct IterMut<'a, T>(Option<&'a mut Node<T>>);
-
-impl<'a, T> Iterator for IterMut<'a, T> {
-    type Item = &'a mut T;
+    /// Inserts a clone of `sep` between each pair of adjacent elements of the [`Queue`]
+    ///
+    /// # Panics
+    /// Panics if the [`Queue`] is bounded and the separators would exceed its capacity,
+    /// the [`Queue`] is left untouched in that case
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let mut queue = Queue::new();
+    ///
+    /// for x in [1, 2, 3] {
+    ///     queue.push(x);
+    /// }
+    ///
+    /// queue.intersperse(0);
+    ///
+    /// let values: Vec<_> = queue.into_iter().collect();
+    /// assert_eq!(vec![1, 0, 2, 0, 3], values);
+    /// ```
+    pub fn intersperse(&mut self, sep: T)
+    where
+        T: Clone,
+    {
+        let new_len = self.len + self.len.saturating_sub(1);
+        if self.capacity.is_some_and(|capacity| new_len > capacity) {
+            panic!("push on a full queue");
+        }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.0.take().map(|node| {
-            unsafe {
-                self.0 = node.next.as_mut();
+        let mut node = self.head;
+        unsafe {
+            while !node.is_null() && !(*node).next.is_null() {
+                let sep_node = self.alloc_node(sep.clone());
+                (*sep_node).next = (*node).next;
+                (*node).next = sep_node;
+                self.len += 1;
+                node = (*sep_node).next;
             }
-            &mut node.value
-        })
+        }
     }
-}
-
-impl<'a, T> IntoIterator for &'a mut Queue<T> {
-    type Item = &'a mut T;
-    type IntoIter = IterMut<'a, T>;
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter_mut()
+    /// Returns the index, from the front, of the first element for which `pred` is false
+    /// assuming the [`Queue`] is partitioned, like [`slice::partition_point`] but via a linear walk
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let mut queue = Queue::new();
+    ///
+    /// for x in [1, 2, 3, 4, 5] {
+    ///     queue.push(x);
+    /// }
+    ///
+    /// assert_eq!(2, queue.partition_point(|x| *x < 3));
+    /// ```
+    pub fn partition_point<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        self.iter().take_while(|value| pred(value)).count()
     }
-}
 
-impl<T> Queue<T> {
-    /// Mutable Reference Iterator to the [`Queue`]
+    /// Applies the fallible `f` to each element from front to back without consuming the [`Queue`]
+    /// Stops at the first `Err` and returns it
     ///
     /// # Example
     /// ```
     /// # use linked_lists_rs::queue::Queue;
     /// let mut queue = Queue::new();
     ///
-    /// // Insert values into the stack
     /// for x in [1, 2, 3] {
     ///     queue.push(x);
     /// }
     ///
-    /// // Use iter_mut to iterate the stack and mutate it's values
-    /// for i in &mut queue {
-    ///     *i *= 2;
-    /// }
+    /// assert_eq!(Ok(()), queue.try_for_each(|x| if *x > 0 { Ok(()) } else { Err(*x) }));
+    /// assert_eq!(Err(2), queue.try_for_each(|x| if *x < 2 { Ok(()) } else { Err(*x) }));
+    /// ```
+    pub fn try_for_each<E, F: FnMut(&T) -> Result<(), E>>(&self, f: F) -> Result<(), E> {
+        self.iter().try_for_each(f)
+    }
+
+    /// Returns a reference to the first element, from front to back, equal to an earlier one
+    /// Returns `None` if all the elements are unique
     ///
-    /// // Assert values mutate as expected
-    /// for x in [2, 4, 6] {
-    ///     assert_eq!(Some(x), queue.pop());
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let mut queue = Queue::new();
+    ///
+    /// for x in [1, 2, 3, 2, 1] {
+    ///     queue.push(x);
     /// }
+    ///
+    /// assert_eq!(Some(&2), queue.first_duplicate());
     /// ```
-    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
-        unsafe { IterMut(self.head.as_mut()) }
+    #[cfg(feature = "std")]
+    pub fn first_duplicate(&self) -> Option<&T>
+    where
+        T: Eq + Hash,
+    {
+        let mut seen = HashSet::with_capacity(self.len);
+        self.iter().find(|value| !seen.insert(*value))
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::Queue;
+    /// Pops and return the value on the front of the [`Queue`] only if it satisfies `pred`
+    /// Returns `None`, leaving the [`Queue`] untouched, if it doesn't or the [`Queue`] is empty
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let mut queue = Queue::new();
+    ///
+    /// queue.push(5);
+    ///
+    /// assert_eq!(None, queue.try_pop_if(|x| *x > 5));
+    /// assert_eq!(Some(5), queue.try_pop_if(|x| *x == 5));
+    /// ```
+    pub fn try_pop_if<F: FnOnce(&T) -> bool>(&mut self, pred: F) -> Option<T> {
+        if pred(self.peek()?) {
+            self.pop()
+        } else {
+            None
+        }
+    }
+
+    /// Moves the first element satisfying `pred` to the front of the [`Queue`]
+    /// keeping the order of the other elements
+    /// Returns `true` if a matching element was found, even if it already was the front
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let mut queue = Queue::from([1, 2, 3]);
+    ///
+    /// assert!(queue.move_to_front(|x| *x == 2));
+    /// assert!(!queue.move_to_front(|x| *x == 4));
+    ///
+    /// assert_eq!(Queue::from([2, 1, 3]), queue);
+    /// ```
+    pub fn move_to_front<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> bool {
+        let mut prev: Link<T> = null_mut();
+        let mut node = self.head;
+        unsafe {
+            while !node.is_null() {
+                if pred(&(*node).value) {
+                    if !prev.is_null() {
+                        let node = Box::into_raw(self.unlink(prev, node));
+                        self.link_front(node);
+                    }
+                    return true;
+                }
+                prev = node;
+                node = (*node).next;
+            }
+        }
+        false
+    }
+
+    /// Return the number of elements satisfying `pred`
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let queue = Queue::from([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(2, queue.count_matching(|x| x % 2 == 0));
+    /// ```
+    pub fn count_matching<F: Fn(&T) -> bool>(&self, pred: F) -> usize {
+        self.iter().filter(|value| pred(value)).count()
+    }
+
+    /// Consumes the [`Queue`] into a [`Vec`]
+    /// Elements are in front to back order, so the front of the [`Queue`] is the first element
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let queue = Queue::from([1, 2, 3]);
+    ///
+    /// assert_eq!(vec![1, 2, 3], queue.into_vec());
+    /// ```
+    pub fn into_vec(self) -> Vec<T> {
+        let mut vec = Vec::with_capacity(self.len);
+        vec.extend(self);
+        vec
+    }
+
+    /// Returns a reference to the last element, the closest to the back, satisfying `pred`
+    /// Returns `None` if no element satisfies it
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let queue = Queue::from([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(Some(&3), queue.rfind(|x| x % 2 == 1));
+    /// ```
+    pub fn rfind<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Option<&T> {
+        self.iter().filter(|value| pred(value)).last()
+    }
+
+    /// Removes consecutive repeated elements, keeping the first of each run
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let mut queue = Queue::from([1, 1, 2, 3, 3, 1]);
+    ///
+    /// queue.dedup();
+    ///
+    /// assert_eq!(vec![1, 2, 3, 1], queue.into_vec());
+    /// ```
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        let mut node = self.head;
+        unsafe {
+            while !node.is_null() && !(*node).next.is_null() {
+                let next = (*node).next;
+                if (*next).value == (*node).value {
+                    drop(self.unlink(node, next));
+                } else {
+                    node = next;
+                }
+            }
+        }
+    }
+
+    /// Replaces the value on the front of the [`Queue`] in place, returning the old one
+    /// Returns `None` and leaves the [`Queue`] untouched if it is empty
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let mut queue = Queue::from([1, 2]);
+    ///
+    /// assert_eq!(Some(1), queue.replace_front(3));
+    ///
+    /// assert_eq!(vec![3, 2], queue.into_vec());
+    /// ```
+    pub fn replace_front(&mut self, value: T) -> Option<T> {
+        self.peek_mut().map(|front| mem::replace(front, value))
+    }
+
+    /// Keeps the first `len` elements of the [`Queue`] and returns the removed ones,
+    /// from front to back
+    /// Returns an empty [`Vec`] if `len` is greater or equal to the length
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let mut queue = Queue::from([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(vec![3, 4], queue.truncate_extract(2));
+    ///
+    /// assert_eq!(vec![1, 2], queue.into_vec());
+    /// ```
+    pub fn truncate_extract(&mut self, len: usize) -> Vec<T> {
+        let mut removed = Vec::new();
+        if len >= self.len {
+            return removed;
+        }
+        removed.reserve(self.len - len);
+
+        unsafe {
+            let mut node = if len == 0 {
+                self.last = null_mut();
+                mem::replace(&mut self.head, null_mut())
+            } else {
+                let mut last = self.head;
+                for _ in 1..len {
+                    last = (*last).next;
+                }
+                self.last = last;
+                mem::replace(&mut (*last).next, null_mut())
+            };
+            while !node.is_null() {
+                removed.push(ptr::read(&(*node).value));
+                let next = (*node).next;
+                self.free_node(node);
+                node = next;
+            }
+        }
+        self.len = len;
+        removed
+    }
+
+    /// Splits the [`Queue`] in two at `index`, the first `index` elements go to the left
+    /// and the rest to the right, both keeping their order
+    /// Both keep the capacity and recycling limit, the recycled nodes stay with the right one
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let queue = Queue::from([1, 2, 3, 4]);
+    ///
+    /// let (left, right) = queue.split_at(1);
+    ///
+    /// assert_eq!(vec![1], left.into_vec());
+    /// assert_eq!(vec![2, 3, 4], right.into_vec());
+    /// ```
+    pub fn split_at(mut self, index: usize) -> (Queue<T>, Queue<T>) {
+        let count = index.min(self.len);
+        let mut last = null_mut();
+        let mut node = self.head;
+        unsafe {
+            for _ in 0..count {
+                last = node;
+                node = (*node).next;
+            }
+            (self.split_front(last, count), self)
+        }
+    }
+
+    /// Splits the [`Queue`] into `parts` queues of as equal as possible lengths,
+    /// the first ones being the longest
+    /// Every part keeps the capacity and recycling limit of the [`Queue`]
+    /// The first part holds the front of the [`Queue`] and every part keeps the order
+    /// If `parts` is greater than the length, the last parts are empty
+    ///
+    /// # Panics
+    /// Panics if `parts` is zero
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let queue = Queue::from([1, 2, 3, 4, 5]);
+    ///
+    /// let parts: Vec<_> = queue.split_into(2).into_iter().map(Queue::into_vec).collect();
+    ///
+    /// assert_eq!(vec![vec![1, 2, 3], vec![4, 5]], parts);
+    /// ```
+    pub fn split_into(self, parts: usize) -> Vec<Queue<T>> {
+        assert!(parts > 0, "cannot split a queue into zero parts");
+
+        let (size, extra) = (self.len / parts, self.len % parts);
+        let mut queues = Vec::with_capacity(parts);
+        let mut rest = self;
+        for i in 0..parts {
+            let (part, tail) = rest.split_at(size + usize::from(i < extra));
+            queues.push(part);
+            rest = tail;
+        }
+        queues
+    }
+
+    /// Deals the elements of the [`Queue`] round-robin into `n` queues,
+    /// like dealing cards, the `i`-th element from the front goes to the queue `i % n`
+    /// Every queue keeps the relative order of its elements,
+    /// and the capacity and recycling limit of the [`Queue`]
+    ///
+    /// # Panics
+    /// Panics if `n` is zero
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let queue = Queue::from([1, 2, 3, 4, 5]);
+    ///
+    /// let hands: Vec<_> = queue.deal(2).into_iter().map(Queue::into_vec).collect();
+    ///
+    /// assert_eq!(vec![vec![1, 3, 5], vec![2, 4]], hands);
+    /// ```
+    pub fn deal(mut self, n: usize) -> Vec<Queue<T>> {
+        assert!(n > 0, "cannot deal a queue into zero queues");
+
+        let mut queues: Vec<Queue<T>> = (0..n).map(|_| self.empty_like()).collect();
+        let mut node = mem::replace(&mut self.head, null_mut());
+        self.last = null_mut();
+        self.len = 0;
+        unsafe {
+            for i in (0..n).cycle() {
+                if node.is_null() {
+                    break;
+                }
+                let next = (*node).next;
+                queues[i].link_back(node);
+                node = next;
+            }
+        }
+        queues
+    }
+
+    /// Removes and returns the first element, from the front, satisfying `pred`
+    /// Returns `None` if no element satisfies it
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let mut queue = Queue::from([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(Some(2), queue.remove_first_matching(|x| x % 2 == 0));
+    /// assert_eq!(None, queue.remove_first_matching(|x| *x > 4));
+    ///
+    /// assert_eq!(vec![1, 3, 4], queue.into_vec());
+    /// ```
+    pub fn remove_first_matching<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Option<T> {
+        let mut prev: Link<T> = null_mut();
+        let mut node = self.head;
+        unsafe {
+            while !node.is_null() {
+                if pred(&(*node).value) {
+                    return Some(self.unlink(prev, node).value);
+                }
+                prev = node;
+                node = (*node).next;
+            }
+        }
+        None
+    }
+
+    /// Replaces every element equal to `from` with a clone of `to`, in place
+    /// Returns the number of replaced elements
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let mut queue = Queue::from([1, 2, 1, 3]);
+    ///
+    /// assert_eq!(2, queue.replace_all(&1, 0));
+    ///
+    /// assert_eq!(vec![0, 2, 0, 3], queue.into_vec());
+    /// ```
+    pub fn replace_all(&mut self, from: &T, to: T) -> usize
+    where
+        T: PartialEq + Clone,
+    {
+        let mut count = 0;
+        for value in self.iter_mut().filter(|value| *value == from) {
+            *value = to.clone();
+            count += 1;
+        }
+        count
+    }
+
+    /// Returns the index, from the front, of the first element satisfying `pred`
+    /// Returns `None` if no element satisfies it
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let queue = Queue::from([1, 2, 3]);
+    ///
+    /// assert_eq!(Some(0), queue.position(|x| *x == 1));
+    /// assert_eq!(None, queue.position(|x| *x == 4));
+    /// ```
+    pub fn position<F: FnMut(&T) -> bool>(&self, pred: F) -> Option<usize> {
+        self.iter().position(pred)
+    }
+
+    /// Return `true` if the [`Queue`] holds the same elements as `slice`, from front to back
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let queue = Queue::from([1, 2, 3]);
+    ///
+    /// assert!(queue.eq_slice(&[1, 2, 3]));
+    /// assert!(!queue.eq_slice(&[1, 2]));
+    /// ```
+    pub fn eq_slice(&self, slice: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.len == slice.len() && self.iter().eq(slice)
+    }
+
+    /// Returns references to the elements grouped in chunks of `size`, from front to back,
+    /// without consuming the [`Queue`], the last chunk may be shorter
+    ///
+    /// # Panics
+    /// Panics if `size` is zero
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let queue = Queue::from([1, 2, 3]);
+    ///
+    /// assert_eq!(vec![vec![&1, &2], vec![&3]], queue.peek_chunks(2));
+    /// ```
+    pub fn peek_chunks(&self, size: usize) -> Vec<Vec<&T>> {
+        assert!(size != 0, "chunk size must be non-zero");
+
+        let mut chunks = Vec::with_capacity(self.len.div_ceil(size));
+        let mut iter = self.iter();
+        while iter.len > 0 {
+            chunks.push(iter.by_ref().take(size).collect());
+        }
+        chunks
+    }
+
+    /// Push a new value on the front of the [`Queue`], ahead of every other element
+    /// Useful to requeue an element that must be handled next
+    ///
+    /// # Panics
+    /// Panics if the [`Queue`] is bounded and already at capacity
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let mut queue = Queue::from([1, 2]);
+    ///
+    /// queue.push_front(0);
+    ///
+    /// assert_eq!(vec![0, 1, 2], queue.into_vec());
+    /// ```
+    pub fn push_front(&mut self, value: T) {
+        if self.capacity.is_some_and(|capacity| self.len >= capacity) {
+            panic!("push on a full queue");
+        }
+
+        let node = self.alloc_node(value);
+        unsafe {
+            self.link_front(node);
+        }
+    }
+
+    /// Consumes the [`Queue`] yielding its values from back to front,
+    /// the last pushed value comes first
+    /// Every node is freed upfront as the values are collected before iterating
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let queue = Queue::from([1, 2, 3]);
+    ///
+    /// assert_eq!(vec![3, 2, 1], queue.into_iter_rev().collect::<Vec<_>>());
+    /// ```
+    pub fn into_iter_rev(self) -> impl Iterator<Item = T> {
+        self.into_vec().into_iter().rev()
+    }
+
+    /// Collects references to the elements, from front to back, without consuming the [`Queue`]
+    /// The [`Vec`] is allocated once with the exact length, giving indexed access to the elements
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let queue = Queue::from([1, 2, 3]);
+    ///
+    /// let values = queue.to_vec();
+    ///
+    /// assert_eq!(&2, values[1]);
+    /// ```
+    pub fn to_vec(&self) -> Vec<&T> {
+        let mut values = Vec::with_capacity(self.len);
+        values.extend(self.iter());
+        values
+    }
+
+    /// Consumes the [`Queue`] splitting it into its front value, the middle elements
+    /// and its back value
+    /// A single element is returned as the front, leaving the back as `None`
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let queue = Queue::from([1, 2, 3, 4]);
+    ///
+    /// let (front, middle, back) = queue.split_ends();
+    ///
+    /// assert_eq!(Some(1), front);
+    /// assert_eq!(vec![2, 3], middle.into_vec());
+    /// assert_eq!(Some(4), back);
+    /// ```
+    pub fn split_ends(mut self) -> (Option<T>, Queue<T>, Option<T>) {
+        let front = self.pop();
+        let back = match self.len {
+            0 => None,
+            len => self.truncate_extract(len - 1).pop(),
+        };
+        (front, self, back)
+    }
+
+    // Removes `node` from the chain given its predecessor `prev` (null if `node` is the head)
+    // and return ownership of it, keeping `head`, `last` and `len` consistent.
+    unsafe fn unlink(&mut self, prev: Link<T>, node: Link<T>) -> Box<Node<T>> {
+        let node = Box::from_raw(node);
+        if prev.is_null() {
+            self.head = node.next;
+        } else {
+            (*prev).next = node.next;
+        }
+        if node.next.is_null() {
+            self.last = prev;
+        }
+        self.len -= 1;
+        node
+    }
+
+    // Links a detached `node` before the head, keeping `last` and `len` consistent.
+    unsafe fn link_front(&mut self, node: Link<T>) {
+        (*node).next = self.head;
+        if self.head.is_null() {
+            self.last = node;
+        }
+        self.head = node;
+        self.len += 1;
+    }
+
+    // Links a detached `node` after the last one, keeping `head` and `len` consistent.
+    unsafe fn link_back(&mut self, node: Link<T>) {
+        (*node).next = null_mut();
+        if self.last.is_null() {
+            self.head = node;
+        } else {
+            (*self.last).next = node;
+        }
+        self.last = node;
+        self.len += 1;
+    }
+
+    // Detaches the first `count` nodes, up to and including `last`, into a new queue with the same bounds.
+    // A null `last` detaches nothing.
+    unsafe fn split_front(&mut self, last: Link<T>, count: usize) -> Queue<T> {
+        let mut front = self.empty_like();
+        if last.is_null() {
+            return front;
+        }
+        front.head = self.head;
+        front.last = last;
+        front.len = count;
+
+        self.head = (*last).next;
+        if self.head.is_null() {
+            self.last = null_mut();
+        }
+        self.len -= count;
+        (*last).next = null_mut();
+        front
+    }
+
+    // Creates an empty queue with the same capacity and recycling limit, without pooled nodes.
+    fn empty_like(&self) -> Queue<T> {
+        let mut queue = Queue::new();
+        queue.capacity = self.capacity;
+        queue.max_pooled = self.max_pooled;
+        queue
+    }
+
+    // Allocates a node holding `value`, reusing a pooled one when available.
+    fn alloc_node(&mut self, value: T) -> Link<T> {
+        match self.pool.pop() {
+            Some(node) => {
+                let node = Box::into_raw(node) as Link<T>;
+                unsafe {
+                    node.write(Node::new(value));
+                }
+                node
+            }
+            None => Box::into_raw(Box::new(Node::new(value))),
+        }
+    }
+
+    // Releases a node whose value was already moved out, pooling it while there is room.
+    unsafe fn free_node(&mut self, node: Link<T>) {
+        let node = Box::from_raw(node as *mut MaybeUninit<Node<T>>);
+        if self.pool.len() < self.max_pooled {
+            self.pool.push(node);
+        }
+    }
+}
+
+// The queue uniquely owns its nodes, so it is as thread safe as `T` itself.
+unsafe impl<T: Send> Send for Queue<T> {}
+unsafe impl<T: Sync> Sync for Queue<T> {}
+
+impl Queue<char> {
+    /// Consumes the [`Queue`] into a [`String`] of its characters from front to back,
+    /// preserving the insertion order
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let queue = Queue::from(['a', 'b', 'c']);
+    ///
+    /// assert_eq!("abc", queue.into_string());
+    /// ```
+    pub fn into_string(self) -> String {
+        self.into_iter().collect()
+    }
+}
+
+impl<T> Default for Queue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Lists the values from front to back instead of the raw node pointers.
+impl<T: fmt::Debug> fmt::Debug for Queue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Queue ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for Queue<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other)
+    }
+}
+
+impl<T: Eq> Eq for Queue<T> {}
+
+// Lexicographic order in iteration order, matching `Vec` semantics.
+impl<T: PartialOrd> PartialOrd for Queue<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other)
+    }
+}
+
+impl<T: Ord> Ord for Queue<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other)
+    }
+}
+
+// Pushes the array elements in order, the first element of the array ends up on the front.
+impl<T, const N: usize> From<[T; N]> for Queue<T> {
+    fn from(values: [T; N]) -> Self {
+        let mut queue = Self::new();
+        for value in values {
+            queue.push(value);
+        }
+        queue
+    }
+}
+
+// Values are pushed in order, panicking like `push` if a bounded queue gets full.
+impl<T> Extend<T> for Queue<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for Queue<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+// A plain `Vec` carries no capacity, so the bound is given alongside it as a `(values, capacity)` pair.
+impl<T> TryFrom<(Vec<T>, usize)> for Queue<T> {
+    type Error = Vec<T>;
+
+    /// Creates a bounded [`Queue`] of `capacity` holding `values`, front to back
+    /// Returns `values` back as `Err` if they don't fit the capacity
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let queue = Queue::try_from((vec![1, 2], 3)).unwrap();
+    /// assert_eq!(Some(3), queue.capacity());
+    /// assert_eq!(vec![1, 2], queue.into_vec());
+    ///
+    /// assert_eq!(Err(vec![1, 2]), Queue::try_from((vec![1, 2], 1)));
+    /// ```
+    fn try_from((values, capacity): (Vec<T>, usize)) -> Result<Self, Self::Error> {
+        if values.len() > capacity {
+            return Err(values);
+        }
+
+        let mut queue = Self::with_capacity(capacity);
+        for value in values {
+            queue.push(value);
+        }
+        Ok(queue)
+    }
+}
+
+// Custom code within the destructor.
+impl<T> Drop for Queue<T> {
+    fn drop(&mut self) {
+        while self.pop().is_some() {}
+    }
+}
+
+/// [`IntoIter`] struct for [`Queue`] consumed iteration
+/// Iterate from front to end
+pub struct IntoIter<T>(Queue<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+
+    fn count(self) -> usize {
+        self.0.len
+    }
+}
+
+impl<T> IntoIterator for Queue<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Iterator to the [`Queue`]
+    /// Consumes the data structure on iteration
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let mut queue = Queue::new();
+    ///
+    /// // Insert values into the queue
+    /// for x in [1, 2, 3] {
+    ///     queue.push(x);
+    /// }
+    ///
+    /// // Iterate the queue and verify its values
+    /// for (i, x) in std::iter::zip(queue, [1, 2, 3]) {
+    ///     assert_eq!(i, x);
+    /// }
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+/// [`Iter`] struct for [`Queue`] referenced iteration
+/// Iterate from front to end, or from end to front with [`Iterator::rev`]
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+    len: usize,
+    // Remaining nodes, only collected on the first `next_back` as nodes don't link backwards
+    nodes: Option<VecDeque<&'a Node<T>>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(nodes) = &mut self.nodes {
+            let node = nodes.pop_front()?;
+            self.len -= 1;
+            return Some(&node.value);
+        }
+        self.next.take().map(|node| {
+            unsafe {
+                self.next = node.next.as_ref();
+            }
+            self.len -= 1;
+            &node.value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn count(self) -> usize {
+        self.len
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let next = &mut self.next;
+        let nodes = self.nodes.get_or_insert_with(|| {
+            let mut nodes = VecDeque::with_capacity(self.len);
+            while let Some(node) = next.take() {
+                nodes.push_back(node);
+                *next = unsafe { node.next.as_ref() };
+            }
+            nodes
+        });
+        let node = nodes.pop_back()?;
+        self.len -= 1;
+        Some(&node.value)
+    }
+}
+
+// Manual impl as deriving would needlessly require `T: Clone`.
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter {
+            next: self.next,
+            len: self.len,
+            nodes: self.nodes.clone(),
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Queue<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> Queue<T> {
+    /// Reference Iterator to the [`Queue`]
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let mut queue = Queue::new();
+    ///
+    /// // Insert values into the stack
+    /// for x in [1, 2, 3] {
+    ///     queue.push(x);
+    /// }
+    ///
+    /// // Use iter to iterate the stack and verify its values
+    /// for (i, x) in std::iter::zip(&queue, [1, 2, 3]) {
+    ///     assert_eq!(i, &x);
+    /// }
+    ///
+    /// // Stack is not consumed
+    /// assert_eq!(Some(&1), queue.peek());
+    ///
+    /// // Iterate backwards
+    /// assert_eq!(vec![&3, &2, &1], queue.iter().rev().collect::<Vec<_>>());
+    /// ```
+    ///
+    /// Iterating backwards has a one-time O(n) time and memory cost, the first call to
+    /// `next_back` collects the remaining nodes as they only link forward,
+    /// after that each step from either end is O(1)
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: unsafe { self.head.as_ref() },
+            len: self.len,
+            nodes: None,
+        }
+    }
+}
+
+/// [`IterMut`] struct for [`Queue`] mutable referenced iteration
+/// Iterate from front to end
+pub struct IterMut<'a, T> {
+    next: Option<&'a mut Node<T>>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.take().map(|node| {
+            unsafe {
+                self.next = node.next.as_mut();
+            }
+            self.len -= 1;
+            &mut node.value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn count(self) -> usize {
+        self.len
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Queue<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> Queue<T> {
+    /// Mutable Reference Iterator to the [`Queue`]
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let mut queue = Queue::new();
+    ///
+    /// // Insert values into the stack
+    /// for x in [1, 2, 3] {
+    ///     queue.push(x);
+    /// }
+    ///
+    /// // Use iter_mut to iterate the stack and mutate it's values
+    /// for i in &mut queue {
+    ///     *i *= 2;
+    /// }
+    ///
+    /// // Assert values mutate as expected
+    /// for x in [2, 4, 6] {
+    ///     assert_eq!(Some(x), queue.pop());
+    /// }
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            next: unsafe { self.head.as_mut() },
+            len: self.len,
+        }
+    }
+}
+
+/// [`Drain`] struct for [`Queue`] draining iteration
+/// Iterate from front to end, removing the remaining elements when dropped
+pub struct Drain<'a, T>(&'a mut Queue<T>);
+
+impl<'a, T> Iterator for Drain<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+
+    fn count(self) -> usize {
+        self.0.len
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Drain<'a, T> {}
+
+impl<'a, T> Drop for Drain<'a, T> {
+    fn drop(&mut self) {
+        while self.0.pop().is_some() {}
+    }
+}
+
+impl<T> Queue<T> {
+    /// Draining Iterator to the [`Queue`]
+    /// Removes the elements on iteration but, unlike `into_iter`, keeps the [`Queue`] usable
+    /// Elements not consumed are removed when the [`Drain`] is dropped
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::queue::Queue;
+    /// let mut queue = Queue::new();
+    ///
+    /// for x in [1, 2, 3] {
+    ///     queue.push(x);
+    /// }
+    ///
+    /// // Take only the first element, the rest is dropped with the iterator
+    /// assert_eq!(Some(1), queue.drain().next());
+    /// assert!(queue.is_empty());
+    ///
+    /// // Queue can be reused
+    /// queue.push(4);
+    /// assert_eq!(Some(4), queue.pop());
+    /// ```
+    pub fn drain(&mut self) -> Drain<'_, T> {
+        Drain(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Queue;
+    use crate::counting_allocator::allocations;
+    use std::cmp::Ordering;
+
+    #[test]
+    fn basics() {
+        let mut list = Queue::new();
+
+        // Check empty list behaves right
+        assert_eq!(list.pop(), None);
+
+        // Populate list
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        // Check normal removal
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), Some(2));
+
+        // Push some more just to make sure nothing's corrupted
+        list.push(4);
+        list.push(5);
+
+        // Check normal removal
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(4));
+
+        // Check exhaustion
+        assert_eq!(list.pop(), Some(5));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn peek() {
+        let mut list = Queue::new();
+        assert_eq!(list.peek(), None);
+        assert_eq!(list.peek_mut(), None);
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        assert_eq!(list.peek(), Some(&1));
+        assert_eq!(list.peek_mut(), Some(&mut 1));
+        list.peek_mut().map(|value| *value = 42);
+
+        assert_eq!(list.peek(), Some(&42));
+        assert_eq!(list.pop(), Some(42));
+    }
+
+    #[test]
+    fn into_iter() {
+        let mut list = Queue::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn iter() {
+        let mut list = Queue::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut list = Queue::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        let mut iter = list.iter_mut();
+        assert_eq!(iter.next(), Some(&mut 1));
+        assert_eq!(iter.next(), Some(&mut 2));
+        assert_eq!(iter.next(), Some(&mut 3));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn len() {
+        let mut list = Queue::new();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+
+        list.push(1);
+        list.push(2);
+        assert_eq!(list.len(), 2);
+
+        list.pop();
+        assert_eq!(list.len(), 1);
+        list.pop();
+        list.pop();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn bounded() {
+        let mut list = Queue::with_capacity(3);
+        assert_eq!(list.capacity(), Some(3));
+
+        // Fill to capacity
+        assert_eq!(list.try_push(1), Ok(()));
+        assert_eq!(list.try_push(2), Ok(()));
+        assert_eq!(list.try_push(3), Ok(()));
+
+        // Check rejection when full
+        assert_eq!(list.try_push(4), Err(4));
+        assert_eq!(list.len(), 3);
+
+        // Pop makes room again
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.try_push(4), Ok(()));
+        assert_eq!(list.try_push(5), Err(5));
+
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(4));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn unbounded_try_push() {
+        let mut list = Queue::new();
+        assert_eq!(list.capacity(), None);
+        for x in 0..100 {
+            assert_eq!(list.try_push(x), Ok(()));
+        }
+        assert_eq!(list.len(), 100);
+    }
+
+    #[test]
+    #[should_panic]
+    fn push_on_full() {
+        let mut list = Queue::with_capacity(1);
+        list.push(1);
+        list.push(2);
+    }
+
+    #[test]
+    fn starts_with() {
+        let mut list = Queue::new();
+        let mut prefix = Queue::new();
+        assert!(list.starts_with(&prefix));
+
+        for x in [1, 2, 3, 4] {
+            list.push(x);
+        }
+        assert!(list.starts_with(&prefix));
+
+        // Grow the prefix up to the whole queue and past it
+        prefix.push(1);
+        assert!(list.starts_with(&prefix));
+        prefix.push(2);
+        assert!(list.starts_with(&prefix));
+        prefix.push(3);
+        prefix.push(4);
+        assert!(list.starts_with(&prefix));
+        prefix.push(5);
+        assert!(!list.starts_with(&prefix));
+
+        let mut other = Queue::new();
+        other.push(2);
+        assert!(!list.starts_with(&other));
+        other.push(3);
+        assert!(!list.starts_with(&other));
+    }
+
+    #[test]
+    fn ends_with() {
+        let mut list = Queue::new();
+        let mut suffix = Queue::new();
+        assert!(list.ends_with(&suffix));
+
+        for x in [1, 2, 3, 4] {
+            list.push(x);
+        }
+        assert!(list.ends_with(&suffix));
+
+        suffix.push(4);
+        assert!(list.ends_with(&suffix));
+
+        let mut other = Queue::new();
+        other.push(3);
+        assert!(!list.ends_with(&other));
+        other.push(4);
+        assert!(list.ends_with(&other));
+
+        let mut other = Queue::new();
+        for x in [1, 2] {
+            other.push(x);
+        }
+        assert!(!list.ends_with(&other));
+
+        let mut other = Queue::new();
+        for x in [0, 1, 2, 3, 4] {
+            other.push(x);
+        }
+        assert!(!list.ends_with(&other));
+        other.pop();
+        assert!(list.ends_with(&other));
+    }
+
+    #[test]
+    fn retain() {
+        let mut list = Queue::from([1, 2, 3, 4, 5, 6]);
+        list.retain(|_| true);
+        assert_eq!(list.len(), 6);
+
+        list.retain(|x| x % 2 == 0);
+        assert_eq!(list.len(), 3);
+
+        // Make sure last is still valid
+        list.push(8);
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(4));
+        assert_eq!(list.pop(), Some(6));
+        assert_eq!(list.pop(), Some(8));
+        assert_eq!(list.pop(), None);
+
+        let mut list = Queue::from([1, 2, 3]);
+        list.retain(|_| false);
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.pop(), None);
+        list.push(4);
+        assert_eq!(list.pop(), Some(4));
+    }
+
+    #[test]
+    fn drain() {
+        let mut list = Queue::from([1, 2, 3]);
+
+        let mut drain = list.drain();
+        assert_eq!(drain.len(), 3);
+        assert_eq!(drain.next(), Some(1));
+        assert_eq!(drain.size_hint(), (2, Some(2)));
+        assert_eq!(drain.next(), Some(2));
+        assert_eq!(drain.next(), Some(3));
+        assert_eq!(drain.len(), 0);
+        assert_eq!(drain.next(), None);
+        drop(drain);
+
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.peek(), None);
+    }
+
+    #[test]
+    fn drain_partial() {
+        let mut list = Queue::from([1, 2, 3]);
+
+        let mut drain = list.drain();
+        assert_eq!(drain.next(), Some(1));
+        drop(drain);
+
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn drain_reuse() {
+        let mut list = Queue::from([1, 2, 3]);
+        assert_eq!(list.drain().collect::<Vec<_>>(), vec![1, 2, 3]);
+
+        list.push(4);
+        list.push(5);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.pop(), Some(4));
+        assert_eq!(list.pop(), Some(5));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn dedup_front() {
+        let mut list = Queue::from([1, 1, 1, 2, 2, 1]);
+        list.dedup_front();
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(1));
+
+        let mut list = Queue::from([3, 3]);
+        list.dedup_front();
+        assert_eq!(list.len(), 1);
+        list.push(4);
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(4));
+    }
+
+    #[test]
+    fn dedup_front_without_duplicates() {
+        let mut list = Queue::from([1, 2, 2]);
+        list.dedup_front();
+        assert_eq!(list.len(), 3);
+
+        let mut list: Queue<i32> = Queue::new();
+        list.dedup_front();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn const_new() {
+        const QUEUE: Queue<i32> = Queue::new();
+        let mut list = QUEUE;
+        assert!(list.is_empty());
+        list.push(1);
+        assert_eq!(list.pop(), Some(1));
+    }
+
+    #[test]
+    fn eq() {
+        assert_eq!(Queue::from([1, 2]), Queue::from([1, 2]));
+        assert_ne!(Queue::from([1, 2]), Queue::from([2, 1]));
+        assert_ne!(Queue::from([1, 2]), Queue::from([1]));
+        assert_eq!(Queue::<i32>::new(), Queue::<i32>::new());
+    }
+
+    #[test]
+    fn ord_prefix() {
+        // Compares front to back, a prefix is less
+        assert!(Queue::from([1]) < Queue::from([1, 2]));
+        assert!(Queue::<i32>::new() < Queue::from([1]));
+        assert_eq!(
+            Queue::from([1, 2]).cmp(&Queue::from([1, 2])),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn ord_elements() {
+        assert!(Queue::from([1, 9]) < Queue::from([2, 0]));
+        assert!(Queue::from([3, 1]) > Queue::from([2, 2, 2]));
+
+        let mut queues = vec![Queue::from([3]), Queue::from([1, 2]), Queue::from([1])];
+        queues.sort();
+        assert_eq!(
+            queues,
+            vec![Queue::from([1]), Queue::from([1, 2]), Queue::from([3])]
+        );
+    }
+
+    #[test]
+    fn take_up_to_limit() {
+        let mut list = Queue::from([1, 2, 3, 4]);
+        let mut taken = list.take_up_to(2, |_| true);
+        assert_eq!(taken.len(), 2);
+        assert_eq!(list.len(), 2);
+
+        // Both last pointers are valid
+        taken.push(5);
+        list.push(6);
+        assert_eq!(taken, Queue::from([1, 2, 5]));
+        assert_eq!(list, Queue::from([3, 4, 6]));
+    }
+
+    #[test]
+    fn take_up_to_predicate() {
+        let mut list = Queue::from([1, 2, 3, 1]);
+        let taken = list.take_up_to(10, |x| *x < 3);
+        assert_eq!(taken, Queue::from([1, 2]));
+        assert_eq!(list, Queue::from([3, 1]));
+
+        let taken = list.take_up_to(10, |x| *x < 3);
+        assert!(taken.is_empty());
+        assert_eq!(list, Queue::from([3, 1]));
+    }
+
+    #[test]
+    fn take_up_to_fewer_available() {
+        let mut list = Queue::from([1, 2]);
+        let mut taken = list.take_up_to(5, |_| true);
+        assert_eq!(taken, Queue::from([1, 2]));
+        assert!(list.is_empty());
+
+        list.push(3);
+        taken.push(4);
+        assert_eq!(list, Queue::from([3]));
+        assert_eq!(taken, Queue::from([1, 2, 4]));
+    }
+
+    #[test]
+    fn take_up_to_bounded() {
+        let mut list = Queue::with_capacity(3);
+        for x in [1, 2, 3] {
+            list.push(x);
+        }
+
+        let mut taken = list.take_up_to(2, |_| true);
+        assert_eq!(taken.capacity(), Some(3));
+        assert_eq!(taken.try_push(4), Ok(()));
+        assert_eq!(taken.try_push(5), Err(5));
+    }
+
+    #[test]
+    fn recycling() {
+        let mut list = Queue::with_recycling(4);
+        list.push(1);
+        list.push(2);
+        assert_eq!(list.pop(), Some(1));
+        list.push(3);
+        list.push(4);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list, Queue::from([2, 3, 4]));
+    }
+
+    #[test]
+    fn recycling_reduces_allocations() {
+        let mut list = Queue::with_recycling(4);
+        let before = allocations();
+
+        // Only the first pushes hit the allocator
+        for x in 0..1000 {
+            list.push(x);
+            list.push(x);
+            assert_eq!(list.pop(), Some(x));
+            assert_eq!(list.pop(), Some(x));
+        }
+        assert_eq!(allocations() - before, 2);
+
+        let mut list = Queue::new();
+        let before = allocations();
+        for x in 0..1000 {
+            list.push(x);
+            list.pop();
+        }
+        assert_eq!(allocations() - before, 1000);
+    }
+
+    #[test]
+    fn recycling_pool_limit() {
+        let mut list = Queue::with_recycling(2);
+        let before = allocations();
+
+        for x in 0..4 {
+            list.push(x);
+        }
+        for _ in 0..4 {
+            list.pop();
+        }
+        // Only two nodes were kept, the other two must be allocated again
+        for x in 0..4 {
+            list.push(x);
+        }
+        assert_eq!(allocations() - before, 6);
+    }
+
+    #[test]
+    fn size_hint() {
+        let n = 5;
+        let mut list = Queue::new();
+        for x in 0..n {
+            list.push(x);
+        }
+
+        let mut iter = list.iter();
+        assert_eq!(iter.size_hint(), (n, Some(n)));
+        iter.next();
+        assert_eq!(iter.size_hint(), (n - 1, Some(n - 1)));
+
+        let mut iter = list.iter_mut();
+        assert_eq!(iter.size_hint(), (n, Some(n)));
+        iter.next();
+        assert_eq!(iter.size_hint(), (n - 1, Some(n - 1)));
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.size_hint(), (n, Some(n)));
+        iter.by_ref().for_each(drop);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn intersperse() {
+        let mut list = Queue::from([1, 2, 3]);
+        list.intersperse(0);
+        assert_eq!(list.len(), 5);
+
+        // Make sure last is still valid
+        list.push(4);
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), Some(0));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(0));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(4));
+        assert_eq!(list.pop(), None);
+
+        let mut list = Queue::from([1]);
+        list.intersperse(0);
+        assert_eq!(list, Queue::from([1]));
+
+        let mut list: Queue<i32> = Queue::new();
+        list.intersperse(0);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn intersperse_bounded() {
+        let mut list = Queue::with_capacity(5);
+        for x in 1..=3 {
+            list.push(x);
+        }
+        list.intersperse(0);
+        assert_eq!(list.into_vec(), vec![1, 0, 2, 0, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn intersperse_over_capacity() {
+        let mut list = Queue::with_capacity(4);
+        for x in 1..=3 {
+            list.push(x);
+        }
+        list.intersperse(0);
+    }
+
+    #[test]
+    fn partition_point() {
+        let list = Queue::from([1, 2, 3, 4, 5]);
+        assert_eq!(list.partition_point(|_| true), 5);
+        assert_eq!(list.partition_point(|_| false), 0);
+        assert_eq!(list.partition_point(|x| *x < 4), 3);
+
+        let list: Queue<i32> = Queue::new();
+        assert_eq!(list.partition_point(|_| true), 0);
+    }
+
+    #[test]
+    fn try_for_each() {
+        let list = Queue::from([1, 2, 3]);
+        let mut seen = Vec::new();
+        let result: Result<(), ()> = list.try_for_each(|x| {
+            seen.push(*x);
+            Ok(())
+        });
+        assert_eq!(result, Ok(()));
+        assert_eq!(seen, vec![1, 2, 3]);
+    }
 
     #[test]
-    fn basics() {
-        let mut list = Queue::new();
+    fn try_for_each_error() {
+        let list = Queue::from([1, 2, 3]);
+        let mut seen = Vec::new();
+        let result = list.try_for_each(|x| {
+            seen.push(*x);
+            if *x == 2 {
+                Err("invalid")
+            } else {
+                Ok(())
+            }
+        });
+        assert_eq!(result, Err("invalid"));
+        assert_eq!(seen, vec![1, 2]);
 
-        // Check empty list behaves right
-        assert_eq!(list.pop(), None);
+        // Queue is unchanged
+        assert_eq!(list, Queue::from([1, 2, 3]));
+    }
 
-        // Populate list
-        list.push(1);
-        list.push(2);
-        list.push(3);
+    #[test]
+    #[cfg(feature = "std")]
+    fn first_duplicate() {
+        assert_eq!(Queue::from([1, 1, 2, 3, 2]).first_duplicate(), Some(&1));
+        assert_eq!(Queue::from([1, 2, 3, 4, 2]).first_duplicate(), Some(&2));
+        assert_eq!(Queue::from([1, 2, 3, 4]).first_duplicate(), None);
+        assert_eq!(Queue::<i32>::new().first_duplicate(), None);
+    }
 
-        // Check normal removal
+    #[test]
+    fn try_pop_if() {
+        let mut list = Queue::from([1, 2]);
+        assert_eq!(list.try_pop_if(|x| *x == 1), Some(1));
+        assert_eq!(list.len(), 1);
+
+        assert_eq!(list.try_pop_if(|x| *x == 1), None);
+        assert_eq!(list, Queue::from([2]));
+
+        assert_eq!(list.try_pop_if(|_| true), Some(2));
+        assert_eq!(list.try_pop_if(|_| true), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn from_array() {
+        let list: Queue<i32> = Queue::from([]);
+        assert!(list.is_empty());
+
+        let list = Queue::from([1]);
+        assert_eq!(list, Queue::from([1]));
+
+        let mut list = Queue::from([1, 2, 3]);
+        assert_eq!(list.len(), 3);
         assert_eq!(list.pop(), Some(1));
         assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), None);
+    }
 
-        // Push some more just to make sure nothing's corrupted
+    #[test]
+    fn move_to_front() {
+        let mut list = Queue::from([1, 2, 3, 4]);
+        assert!(list.move_to_front(|x| *x == 3));
+        assert_eq!(list.len(), 4);
+        assert_eq!(list, Queue::from([3, 1, 2, 4]));
+
+        assert!(list.move_to_front(|x| *x == 3));
+        assert_eq!(list, Queue::from([3, 1, 2, 4]));
+    }
+
+    #[test]
+    fn move_to_front_back() {
+        let mut list = Queue::from([1, 2, 3]);
+        assert!(list.move_to_front(|x| *x == 3));
+
+        // Make sure last is still valid
         list.push(4);
-        list.push(5);
+        assert_eq!(list, Queue::from([3, 1, 2, 4]));
+    }
 
-        // Check normal removal
-        assert_eq!(list.pop(), Some(3));
-        assert_eq!(list.pop(), Some(4));
+    #[test]
+    fn move_to_front_no_match() {
+        let mut list = Queue::from([1, 2, 3]);
+        assert!(!list.move_to_front(|x| *x > 3));
+        assert_eq!(list, Queue::from([1, 2, 3]));
 
-        // Check exhaustion
-        assert_eq!(list.pop(), Some(5));
-        assert_eq!(list.pop(), None);
+        let mut list: Queue<i32> = Queue::new();
+        assert!(!list.move_to_front(|_| true));
     }
 
     #[test]
-    fn peek() {
+    fn count_matching() {
+        assert_eq!(Queue::<i32>::new().count_matching(|_| true), 0);
+        assert_eq!(Queue::from([1, 2, 3]).count_matching(|_| true), 3);
+        assert_eq!(Queue::from([1, 2, 3, 4, 5]).count_matching(|x| *x > 2), 3);
+        assert_eq!(Queue::from([1, 2, 3]).count_matching(|x| *x > 3), 0);
+    }
+
+    #[test]
+    fn into_vec() {
+        let list = Queue::from([1, 2, 3]);
+        assert_eq!(list.into_vec(), vec![1, 2, 3]);
+
+        let list: Queue<i32> = Queue::new();
+        assert_eq!(list.into_vec(), vec![]);
+    }
+
+    #[test]
+    fn rfind() {
+        let list = Queue::from([(1, 'a'), (2, 'b'), (1, 'c'), (3, 'd')]);
+        assert_eq!(list.rfind(|(x, _)| *x == 1), Some(&(1, 'c')));
+        assert_eq!(list.rfind(|(x, _)| *x == 2), Some(&(2, 'b')));
+        assert_eq!(list.rfind(|(x, _)| *x == 4), None);
+        assert_eq!(Queue::<i32>::new().rfind(|_| true), None);
+    }
+
+    #[test]
+    fn dedup() {
+        let mut list = Queue::from([1, 1, 2, 3, 3, 3, 1, 2, 2]);
+        list.dedup();
+        assert_eq!(list.len(), 5);
+
+        // Make sure last is still valid
+        list.push(4);
+        assert_eq!(list.into_vec(), vec![1, 2, 3, 1, 2, 4]);
+    }
+
+    #[test]
+    fn dedup_without_duplicates() {
+        let mut list = Queue::from([1, 2, 3]);
+        list.dedup();
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.into_vec(), vec![1, 2, 3]);
+
+        let mut list: Queue<i32> = Queue::new();
+        list.dedup();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn dedup_all_identical() {
+        let mut list = Queue::from([7, 7, 7, 7]);
+        list.dedup();
+        assert_eq!(list.len(), 1);
+        list.push(8);
+        assert_eq!(list.into_vec(), vec![7, 8]);
+    }
+
+    #[test]
+    fn into_string() {
         let mut list = Queue::new();
-        assert_eq!(list.peek(), None);
-        assert_eq!(list.peek_mut(), None);
-        list.push(1);
-        list.push(2);
-        list.push(3);
+        for c in "hello".chars() {
+            list.push(c);
+        }
+        assert_eq!(list.into_string(), "hello");
 
-        assert_eq!(list.peek(), Some(&1));
-        assert_eq!(list.peek_mut(), Some(&mut 1));
-        list.peek_mut().map(|value| *value = 42);
+        let list: Queue<char> = Queue::new();
+        assert_eq!(list.into_string(), "");
+    }
 
-        assert_eq!(list.peek(), Some(&42));
-        assert_eq!(list.pop(), Some(42));
+    #[test]
+    fn replace_front() {
+        let mut list = Queue::from([1, 2, 3]);
+        let before = allocations();
+        assert_eq!(list.replace_front(4), Some(1));
+        assert_eq!(allocations(), before);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.into_vec(), vec![4, 2, 3]);
+
+        let mut list = Queue::new();
+        assert_eq!(list.replace_front(1), None);
+        assert!(list.is_empty());
+        assert_eq!(list.pop(), None);
     }
 
     #[test]
-    fn into_iter() {
+    fn truncate_extract() {
+        let mut list = Queue::from([1, 2, 3, 4, 5]);
+        assert_eq!(list.truncate_extract(2), vec![3, 4, 5]);
+        assert_eq!(list.len(), 2);
+
+        // Make sure last is still valid
+        list.push(6);
+        assert_eq!(list.truncate_extract(10), vec![]);
+        assert_eq!(list.len(), 3);
+
+        assert_eq!(list.truncate_extract(0), vec![1, 2, 6]);
+        assert!(list.is_empty());
+        assert_eq!(list.pop(), None);
+        list.push(7);
+        assert_eq!(list.into_vec(), vec![7]);
+    }
+
+    #[test]
+    fn peek_back() {
         let mut list = Queue::new();
+        assert_eq!(list.peek_back(), None);
+
         list.push(1);
         list.push(2);
-        list.push(3);
+        assert_eq!(list.peek_back(), Some(&2));
+        list.pop();
+        list.pop();
+        assert_eq!(list.peek_back(), None);
+    }
 
-        let mut iter = list.into_iter();
-        assert_eq!(iter.next(), Some(1));
-        assert_eq!(iter.next(), Some(2));
-        assert_eq!(iter.next(), Some(3));
+    #[test]
+    fn split_at() {
+        let (mut left, mut right) = Queue::from([1, 2, 3, 4, 5]).split_at(2);
+        assert_eq!(left.len(), 2);
+        assert_eq!(right.len(), 3);
+        assert_eq!(left.peek_back(), Some(&2));
+        assert_eq!(right.peek_back(), Some(&5));
+
+        // Make sure last is still valid on both halves
+        left.push(6);
+        right.push(7);
+        assert_eq!(left.into_vec(), vec![1, 2, 6]);
+        assert_eq!(right.into_vec(), vec![3, 4, 5, 7]);
+    }
+
+    #[test]
+    fn split_at_boundaries() {
+        let (left, right) = Queue::from([1, 2]).split_at(0);
+        assert!(left.is_empty());
+        assert_eq!(left.peek_back(), None);
+        assert_eq!(right.into_vec(), vec![1, 2]);
+
+        let (left, mut right) = Queue::from([1, 2]).split_at(5);
+        assert_eq!(left.peek_back(), Some(&2));
+        assert_eq!(left.into_vec(), vec![1, 2]);
+        assert!(right.is_empty());
+        assert_eq!(right.peek_back(), None);
+        right.push(3);
+        assert_eq!(right.into_vec(), vec![3]);
+    }
+
+    #[test]
+    fn split_at_bounded() {
+        let mut list = Queue::with_capacity(3);
+        for x in [1, 2, 3] {
+            list.push(x);
+        }
+
+        let (mut left, mut right) = list.split_at(1);
+        assert_eq!(left.capacity(), Some(3));
+        assert_eq!(right.capacity(), Some(3));
+        assert_eq!(left.try_push(4), Ok(()));
+        assert_eq!(left.try_push(5), Ok(()));
+        assert_eq!(left.try_push(6), Err(6));
+        assert_eq!(right.try_push(7), Ok(()));
+        assert_eq!(right.try_push(8), Err(8));
+    }
+
+    #[test]
+    fn split_into() {
+        let parts = Queue::from([1, 2, 3, 4, 5, 6, 7]).split_into(3);
+        let lens: Vec<_> = parts.iter().map(Queue::len).collect();
+        assert_eq!(lens, vec![3, 2, 2]);
+
+        let values: Vec<_> = parts.into_iter().flat_map(Queue::into_vec).collect();
+        assert_eq!(values, vec![1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn split_into_more_parts_than_elements() {
+        let mut parts = Queue::from([1, 2]).split_into(4);
+        assert_eq!(parts.len(), 4);
+        assert_eq!(parts[0].pop(), Some(1));
+        assert_eq!(parts[1].pop(), Some(2));
+        assert!(parts[2].is_empty());
+        assert!(parts[3].is_empty());
+
+        // Make sure last is still valid on every part
+        for (part, x) in parts.iter_mut().zip(10..) {
+            part.push(x);
+            assert_eq!(part.peek_back(), Some(&x));
+        }
+    }
+
+    #[test]
+    fn split_into_bounded() {
+        let mut list = Queue::with_capacity(4);
+        for x in [1, 2, 3, 4] {
+            list.push(x);
+        }
+
+        for mut part in list.split_into(3) {
+            assert_eq!(part.capacity(), Some(4));
+            while part.len() < 4 {
+                assert_eq!(part.try_push(0), Ok(()));
+            }
+            assert_eq!(part.try_push(5), Err(5));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_into_zero_parts() {
+        Queue::from([1]).split_into(0);
+    }
+
+    #[test]
+    fn debug() {
+        let list = Queue::from([1, 2, 3]);
+        assert_eq!(format!("{list:?}"), "Queue [1, 2, 3]");
+
+        let list: Queue<i32> = Queue::new();
+        assert_eq!(format!("{list:?}"), "Queue []");
+    }
+
+    #[test]
+    fn deal() {
+        let mut hands = Queue::from([1, 2, 3, 4, 5, 6]).deal(3);
+        assert_eq!(hands.len(), 3);
+        assert!(hands.iter().all(|hand| hand.len() == 2));
+
+        // Make sure last is still valid on every hand
+        hands[0].push(7);
+        let hands: Vec<_> = hands.into_iter().map(Queue::into_vec).collect();
+        assert_eq!(hands, vec![vec![1, 4, 7], vec![2, 5], vec![3, 6]]);
+    }
+
+    #[test]
+    fn deal_uneven() {
+        let hands = Queue::from([1, 2, 3, 4, 5]).deal(3);
+        let lens: Vec<_> = hands.iter().map(Queue::len).collect();
+        assert_eq!(lens, vec![2, 2, 1]);
+
+        let hands: Vec<_> = hands.into_iter().map(Queue::into_vec).collect();
+        assert_eq!(hands, vec![vec![1, 4], vec![2, 5], vec![3]]);
+
+        let hands = Queue::from([1]).deal(2);
+        assert!(hands[1].is_empty());
+        assert_eq!(hands[1].peek_back(), None);
+    }
+
+    #[test]
+    fn deal_bounded() {
+        let mut list = Queue::with_capacity(4);
+        for x in [1, 2, 3, 4] {
+            list.push(x);
+        }
+
+        for mut part in list.deal(3) {
+            assert_eq!(part.capacity(), Some(4));
+            while part.len() < 4 {
+                assert_eq!(part.try_push(0), Ok(()));
+            }
+            assert_eq!(part.try_push(5), Err(5));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn deal_zero() {
+        Queue::from([1]).deal(0);
+    }
+
+    #[test]
+    fn iter_clone() {
+        let list = Queue::from([1, 2, 3]);
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+
+        let mut clone = iter.clone();
+        assert_eq!(clone.next(), Some(&2));
+        assert_eq!(clone.next(), Some(&3));
+        assert_eq!(clone.next(), None);
+
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
         assert_eq!(iter.next(), None);
     }
 
     #[test]
-    fn iter() {
-        let mut list = Queue::new();
-        list.push(1);
+    fn remove_first_matching() {
+        let mut list = Queue::from([1, 2, 3, 4, 5]);
+
+        // Middle
+        assert_eq!(list.remove_first_matching(|x| *x == 3), Some(3));
+        assert_eq!(list.len(), 4);
+
+        // Head
+        assert_eq!(list.remove_first_matching(|x| *x == 1), Some(1));
+        assert_eq!(list.peek(), Some(&2));
+
+        // Tail, make sure last is still valid
+        assert_eq!(list.remove_first_matching(|x| *x == 5), Some(5));
+        assert_eq!(list.peek_back(), Some(&4));
+        list.push(6);
+
+        // Not found
+        assert_eq!(list.remove_first_matching(|x| *x > 10), None);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.into_vec(), vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn remove_first_matching_only_element() {
+        let mut list = Queue::from([1]);
+        assert_eq!(list.remove_first_matching(|_| true), Some(1));
+        assert!(list.is_empty());
+        assert_eq!(list.peek_back(), None);
+        assert_eq!(list.remove_first_matching(|_| true), None);
+
         list.push(2);
-        list.push(3);
+        assert_eq!(list.into_vec(), vec![2]);
+    }
+
+    #[test]
+    fn replace_all() {
+        let mut list = Queue::from([1, 2, 1, 3, 1]);
+        assert_eq!(list.replace_all(&1, 9), 3);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.peek_back(), Some(&9));
+        assert_eq!(list.into_vec(), vec![9, 2, 9, 3, 9]);
+
+        let mut list = Queue::from([1, 2, 3]);
+        assert_eq!(list.replace_all(&4, 9), 0);
+        assert_eq!(list.into_vec(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn position() {
+        let list = Queue::from([1, 2, 3, 4]);
+        assert_eq!(list.position(|x| *x == 1), Some(0));
+        assert_eq!(list.position(|x| *x == 3), Some(2));
+        assert_eq!(list.position(|x| *x == 4), Some(3));
+        assert_eq!(list.position(|x| *x == 5), None);
+        assert_eq!(list.position(|x| x % 2 == 0), Some(1));
+    }
+
+    #[test]
+    fn eq_slice() {
+        let list = Queue::from([1, 2, 3]);
+        assert!(list.eq_slice(&[1, 2, 3]));
+
+        // Length mismatch
+        assert!(!list.eq_slice(&[1, 2]));
+        assert!(!list.eq_slice(&[1, 2, 3, 4]));
+
+        // Element mismatch
+        assert!(!list.eq_slice(&[1, 4, 3]));
+        assert!(!list.eq_slice(&[3, 2, 1]));
+
+        assert!(Queue::<i32>::new().eq_slice(&[]));
+    }
+
+    #[test]
+    fn iter_rev() {
+        let list = Queue::from([1, 2, 3]);
+        let values: Vec<_> = list.iter().rev().collect();
+        assert_eq!(values, vec![&3, &2, &1]);
+        assert_eq!(Queue::<i32>::new().iter().next_back(), None);
+    }
 
+    #[test]
+    fn iter_both_ends_meet() {
+        let list = Queue::from([1, 2, 3, 4, 5]);
         let mut iter = list.iter();
         assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&5));
         assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+
+        // The clone keeps its own copy of the collected nodes
+        let mut clone = iter.clone();
+        assert_eq!(clone.next_back(), Some(&4));
+        assert_eq!(clone.next_back(), Some(&3));
+        assert_eq!(clone.next(), None);
+
+        assert_eq!(iter.next_back(), Some(&4));
         assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.size_hint(), (0, Some(0)));
         assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
     }
 
     #[test]
-    fn iter_mut() {
+    fn peek_chunks() {
+        let list = Queue::from([1, 2, 3, 4, 5, 6]);
+        assert_eq!(
+            list.peek_chunks(3),
+            vec![vec![&1, &2, &3], vec![&4, &5, &6]]
+        );
+        assert_eq!(list.peek_chunks(6), vec![vec![&1, &2, &3, &4, &5, &6]]);
+        assert_eq!(list.len(), 6);
+
+        assert_eq!(
+            list.peek_chunks(4),
+            vec![vec![&1, &2, &3, &4], vec![&5, &6]]
+        );
+        assert_eq!(list.peek_chunks(10), vec![vec![&1, &2, &3, &4, &5, &6]]);
+        assert!(Queue::<i32>::new().peek_chunks(2).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn peek_chunks_zero() {
+        Queue::from([1]).peek_chunks(0);
+    }
+
+    #[test]
+    fn push_front() {
         let mut list = Queue::new();
-        list.push(1);
-        list.push(2);
+        list.push_front(2);
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.peek(), Some(&2));
+        assert_eq!(list.peek_back(), Some(&2));
+
+        list.push_front(1);
         list.push(3);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn push_front_full() {
+        let mut list = Queue::with_capacity(1);
+        list.push(1);
+        list.push_front(2);
+    }
+
+    #[test]
+    fn count() {
+        let mut list = Queue::from([1, 2, 3, 4]);
+        assert_eq!(list.iter().count(), list.len());
+        assert_eq!(list.iter_mut().count(), 4);
+
+        let mut iter = list.iter();
+        iter.next();
+        assert_eq!(iter.count(), 3);
 
         let mut iter = list.iter_mut();
-        assert_eq!(iter.next(), Some(&mut 1));
-        assert_eq!(iter.next(), Some(&mut 2));
-        assert_eq!(iter.next(), Some(&mut 3));
+        iter.next();
+        iter.next();
+        assert_eq!(iter.count(), 2);
+
+        let mut iter = list.into_iter();
+        iter.next();
+        assert_eq!(iter.count(), 3);
+    }
+
+    #[test]
+    fn into_iter_rev() {
+        let mut iter = Queue::from([1, 2, 3]).into_iter_rev();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(1));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn into_iter_rev_partial() {
+        use std::rc::Rc;
+
+        let value = Rc::new(());
+        let mut list = Queue::new();
+        for _ in 0..3 {
+            list.push(Rc::clone(&value));
+        }
+
+        let mut iter = list.into_iter_rev();
+        drop(iter.next());
+        assert_eq!(Rc::strong_count(&value), 3);
+        drop(iter);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn try_from_vec() {
+        let mut list = Queue::try_from((vec![1, 2, 3], 3)).unwrap();
+        assert_eq!(list.capacity(), Some(3));
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.try_push(4), Err(4));
+        assert_eq!(list.into_vec(), vec![1, 2, 3]);
+
+        let list = Queue::try_from((vec![1, 2, 3], 2));
+        assert_eq!(list, Err(vec![1, 2, 3]));
+
+        let mut list = Queue::try_from((Vec::new(), 1)).unwrap();
+        assert!(list.is_empty());
+        assert_eq!(list.try_push(1), Ok(()));
+
+        let list: Queue<i32> = Queue::try_from((Vec::new(), 0)).unwrap();
+        assert_eq!(list.capacity(), Some(0));
+    }
+
+    #[test]
+    fn to_vec() {
+        let mut list = Queue::from([1, 2, 3]);
+        let values = list.to_vec();
+        assert_eq!(values, vec![&1, &2, &3]);
+        assert_eq!(values.capacity(), 3);
+
+        // The queue is still usable
+        list.push(4);
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.to_vec(), vec![&2, &3, &4]);
+        assert!(Queue::<i32>::new().to_vec().is_empty());
+    }
+
+    #[test]
+    fn extend() {
+        let mut list = Queue::from([1]);
+        list.extend(vec![2, 3]);
+        assert_eq!(list.len(), 3);
+
+        let source = [4, 5];
+        list.extend(&source);
+        assert_eq!(source, [4, 5]);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.peek_back(), Some(&5));
+        assert_eq!(list.into_vec(), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn split_ends() {
+        let (front, mut middle, back) = Queue::from([1, 2, 3, 4]).split_ends();
+        assert_eq!(front, Some(1));
+        assert_eq!(back, Some(4));
+        assert_eq!(middle.len(), 2);
+
+        // Make sure last is still valid
+        assert_eq!(middle.peek_back(), Some(&3));
+        middle.push(5);
+        assert_eq!(middle.into_vec(), vec![2, 3, 5]);
+
+        let (front, middle, back) = Queue::from([1, 2]).split_ends();
+        assert_eq!((front, back), (Some(1), Some(2)));
+        assert!(middle.is_empty());
+    }
+
+    #[test]
+    fn split_ends_short() {
+        let (front, middle, back) = Queue::from([1]).split_ends();
+        assert_eq!((front, back), (Some(1), None));
+        assert!(middle.is_empty());
+
+        let (front, mut middle, back) = Queue::<i32>::new().split_ends();
+        assert_eq!((front, back), (None, None));
+        assert!(middle.is_empty());
+        middle.push(1);
+        assert_eq!(middle.peek_back(), Some(&1));
+    }
 }
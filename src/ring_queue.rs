@@ -0,0 +1,365 @@
+//! A [`RingQueue`] is a fixed-capacity First In First Out (FIFO) linear structure.
+//!
+//! It is useful as a bounded log or history buffer, once full it either evicts
+//! the oldest element or rejects the new one, depending on its [`Overflow`] mode.
+//!
+//! This [`RingQueue`] implementation uses the linked list concept.
+//! A fixed pool of nodes is allocated upfront and arranged in a cycle,
+//! so pushing and popping reuse the same nodes without further allocations.
+//! This implementation uses raw pointers and the unsafe keyword
+//! this is so to preserve performance and aims to be a 100% safe abstraction
+//!
+
+use alloc::boxed::Box;
+use core::fmt;
+use core::ptr::null_mut;
+
+type Link<T> = *mut Node<T>;
+
+/// Behaviour of a full [`RingQueue`] when pushing a new value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    /// Evicts the oldest element to make room for the new one
+    Overwrite,
+    /// Rejects the new element, leaving the [`RingQueue`] untouched
+    Reject,
+}
+
+/// RingQueue Struct
+pub struct RingQueue<T> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    capacity: usize,
+    overflow: Overflow,
+}
+
+struct Node<T> {
+    value: Option<T>,
+    next: Link<T>,
+}
+
+impl<T> Node<T> {
+    pub fn new() -> Self {
+        Self {
+            value: None,
+            next: null_mut(),
+        }
+    }
+}
+
+impl<T> RingQueue<T> {
+    /// Creates a new [`RingQueue`] holding at most `capacity` elements
+    /// All the nodes are allocated upfront
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero
+    ///
+    /// # Example
+    /// Creating a new [`RingQueue`] of `i32`
+    /// ```
+    /// use linked_lists_rs::ring_queue::{Overflow, RingQueue};
+    /// let queue: RingQueue<i32> = RingQueue::with_capacity(8, Overflow::Overwrite);
+    /// ```
+    pub fn with_capacity(capacity: usize, overflow: Overflow) -> Self {
+        assert!(capacity > 0, "ring queue capacity must be non-zero");
+
+        let first: *mut _ = Box::into_raw(Box::new(Node::new()));
+        let mut last = first;
+        for _ in 1..capacity {
+            let node: *mut _ = Box::into_raw(Box::new(Node::new()));
+            unsafe {
+                (*last).next = node;
+            }
+            last = node;
+        }
+        unsafe {
+            (*last).next = first;
+        }
+
+        Self {
+            head: first,
+            tail: first,
+            len: 0,
+            capacity,
+            overflow,
+        }
+    }
+
+    /// Push a new value on the end of the [`RingQueue`]
+    /// When full, the oldest value is evicted with [`Overflow::Overwrite`]
+    /// or the value is returned back as `Err` with [`Overflow::Reject`]
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::ring_queue::{Overflow, RingQueue};
+    /// let mut queue = RingQueue::with_capacity(1, Overflow::Overwrite);
+    ///
+    /// assert_eq!(Ok(()), queue.push(5));
+    /// assert_eq!(Ok(()), queue.push(6));
+    ///
+    /// assert_eq!(Some(6), queue.pop());
+    ///
+    /// let mut queue = RingQueue::with_capacity(1, Overflow::Reject);
+    ///
+    /// assert_eq!(Ok(()), queue.push(5));
+    /// assert_eq!(Err(6), queue.push(6));
+    ///
+    /// assert_eq!(Some(5), queue.pop());
+    /// ```
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        if self.len == self.capacity {
+            match self.overflow {
+                Overflow::Reject => return Err(value),
+                Overflow::Overwrite => {
+                    self.pop();
+                }
+            }
+        }
+
+        unsafe {
+            (*self.tail).value = Some(value);
+            self.tail = (*self.tail).next;
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Pops and return the oldest value of the [`RingQueue`]
+    /// Returns `None` if the [`RingQueue`] is empty
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::ring_queue::{Overflow, RingQueue};
+    /// let mut queue = RingQueue::with_capacity(2, Overflow::Overwrite);
+    ///
+    /// queue.push(5).unwrap();
+    ///
+    /// assert_eq!(Some(5), queue.pop());
+    /// assert_eq!(None, queue.pop());
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        unsafe {
+            let value = (*self.head).value.take();
+            self.head = (*self.head).next;
+            self.len -= 1;
+            value
+        }
+    }
+
+    /// Return a reference to the oldest value of the [`RingQueue`]
+    /// Returns `None` if the [`RingQueue`] is empty
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::ring_queue::{Overflow, RingQueue};
+    /// let mut queue = RingQueue::with_capacity(2, Overflow::Overwrite);
+    ///
+    /// queue.push(5).unwrap();
+    ///
+    /// assert_eq!(Some(&5), queue.peek());
+    /// assert_eq!(Some(5), queue.pop());
+    /// assert_eq!(None, queue.peek());
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        unsafe { (*self.head).value.as_ref() }
+    }
+
+    /// Return the number of elements in the [`RingQueue`]
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::ring_queue::{Overflow, RingQueue};
+    /// let mut queue = RingQueue::with_capacity(2, Overflow::Overwrite);
+    ///
+    /// for x in [1, 2, 3] {
+    ///     queue.push(x).unwrap();
+    /// }
+    ///
+    /// assert_eq!(2, queue.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return `true` if the [`RingQueue`] contains no elements
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::ring_queue::{Overflow, RingQueue};
+    /// let mut queue = RingQueue::with_capacity(2, Overflow::Overwrite);
+    /// assert!(queue.is_empty());
+    ///
+    /// queue.push(5).unwrap();
+    /// assert!(!queue.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return the maximum number of elements of the [`RingQueue`]
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::ring_queue::{Overflow, RingQueue};
+    /// let queue: RingQueue<i32> = RingQueue::with_capacity(2, Overflow::Overwrite);
+    ///
+    /// assert_eq!(2, queue.capacity());
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+// Lists the live elements from the oldest, the node pointers are not meaningful to print.
+impl<T: fmt::Debug> fmt::Debug for RingQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RingQueue ")?;
+        let mut list = f.debug_list();
+        let mut node = self.head;
+        for _ in 0..self.len {
+            unsafe {
+                list.entries(&(*node).value);
+                node = (*node).next;
+            }
+        }
+        list.finish()
+    }
+}
+
+// The queue uniquely owns its nodes, so it is as thread safe as `T` itself.
+unsafe impl<T: Send> Send for RingQueue<T> {}
+unsafe impl<T: Sync> Sync for RingQueue<T> {}
+
+// Custom code within the destructor.
+impl<T> Drop for RingQueue<T> {
+    fn drop(&mut self) {
+        let mut node = self.head;
+        for _ in 0..self.capacity {
+            unsafe {
+                let next = (*node).next;
+                drop(Box::from_raw(node));
+                node = next;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Overflow, RingQueue};
+
+    #[test]
+    fn basics() {
+        let mut list = RingQueue::with_capacity(3, Overflow::Reject);
+
+        // Check empty list behaves right
+        assert_eq!(list.pop(), None);
+        assert_eq!(list.peek(), None);
+
+        // Populate list
+        list.push(1).unwrap();
+        list.push(2).unwrap();
+        assert_eq!(list.len(), 2);
+
+        // Check normal removal
+        assert_eq!(list.peek(), Some(&1));
+        assert_eq!(list.pop(), Some(1));
+
+        // Wrap around the cycle
+        list.push(3).unwrap();
+        list.push(4).unwrap();
+        assert_eq!(list.len(), 3);
+
+        // Check exhaustion
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(4));
+        assert_eq!(list.pop(), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn overwrite_evicts_oldest() {
+        let mut list = RingQueue::with_capacity(3, Overflow::Overwrite);
+        for x in 1..=5 {
+            assert_eq!(list.push(x), Ok(()));
+        }
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.capacity(), 3);
+        assert_eq!(list.peek(), Some(&3));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(4));
+        assert_eq!(list.pop(), Some(5));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn reject_keeps_oldest() {
+        let mut list = RingQueue::with_capacity(3, Overflow::Reject);
+        for x in 1..=3 {
+            assert_eq!(list.push(x), Ok(()));
+        }
+        assert_eq!(list.push(4), Err(4));
+        assert_eq!(list.push(5), Err(5));
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.push(6), Ok(()));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(6));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn drops_remaining_values() {
+        use std::rc::Rc;
+
+        let value = Rc::new(());
+        let mut list = RingQueue::with_capacity(2, Overflow::Overwrite);
+        for _ in 0..3 {
+            list.push(value.clone()).unwrap();
+        }
+        assert_eq!(Rc::strong_count(&value), 3);
+
+        drop(list);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_capacity() {
+        RingQueue::<i32>::with_capacity(0, Overflow::Overwrite);
+    }
+
+    #[test]
+    fn debug() {
+        let mut list = RingQueue::with_capacity(3, Overflow::Overwrite);
+        assert_eq!(format!("{list:?}"), "RingQueue []");
+
+        for x in 1..=4 {
+            list.push(x).unwrap();
+        }
+        assert_eq!(format!("{list:?}"), "RingQueue [2, 3, 4]");
+    }
+
+    #[test]
+    fn send_across_threads() {
+        let mut list = RingQueue::with_capacity(2, Overflow::Reject);
+        list.push(1).unwrap();
+
+        let list = std::thread::spawn(move || {
+            list.push(2).unwrap();
+            list
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(format!("{list:?}"), "RingQueue [1, 2]");
+    }
+}
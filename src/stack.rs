@@ -4,23 +4,74 @@
 //! This means the last element inserted inside the stack is removed first.
 //!
 //! This Stack implementation uses the linked list concept.
-//! This implementation is 100% safe rust
+//! This implementation is 100% safe rust, except for the opt-in node pool
+//!
+//! A [`Stack`] created with [`Stack::with_pool`] preallocates spare nodes,
+//! popped node allocations go back to the pool and are reused on push.
+//! Moving a value out of a node while keeping its allocation is the only use of
+//! the unsafe keyword, a [`Stack`] created with [`Stack::new`] never reaches it
 //!
 
-use std::mem;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::mem::{self, MaybeUninit};
+use core::ptr;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "std")]
+use std::hash::Hash;
 
 type Link<T> = Option<Box<Node<T>>>;
 
 /// Stack Struct
-#[derive(Debug)]
-pub struct Stack<T>(Link<T>);
+pub struct Stack<T> {
+    head: Link<T>,
+    len: usize,
+    // Only set by `Stack::with_pool`, so a plain stack pays a single pointer for it
+    pool: Option<Box<NodePool<T>>>,
+}
 
-#[derive(Debug)]
 struct Node<T> {
     value: T,
     next: Link<T>,
 }
 
+// Spare node allocations of a `Stack`, keeping at most `max` of them for reuse.
+struct NodePool<T> {
+    slots: Vec<Box<MaybeUninit<Node<T>>>>,
+    max: usize,
+}
+
+impl<T> NodePool<T> {
+    // Boxes `node`, reusing a pooled allocation when available.
+    fn alloc(pool: &mut Option<Box<Self>>, node: Node<T>) -> Box<Node<T>> {
+        match pool.as_mut().and_then(|pool| pool.slots.pop()) {
+            Some(slot) => Box::write(slot, node),
+            None => Box::new(node),
+        }
+    }
+
+    // Moves the value out of a detached `node`, pooling its allocation while there is room.
+    fn free(pool: &mut Option<Box<Self>>, node: Box<Node<T>>) -> T {
+        let pool = match pool {
+            Some(pool) if pool.slots.len() < pool.max => pool,
+            _ => return node.value,
+        };
+        let node = Box::into_raw(node);
+        // The value is read exactly once and the allocation is kept as uninitialized,
+        // so it is neither dropped twice nor leaked. `next` was already taken, it owns nothing.
+        unsafe {
+            let value = ptr::read(&(*node).value);
+            pool.slots
+                .push(Box::from_raw(node as *mut MaybeUninit<Node<T>>));
+            value
+        }
+    }
+}
+
 impl<T> Stack<T> {
     /// Creates a new [`Stack`]
     ///
@@ -30,8 +81,37 @@ impl<T> Stack<T> {
     /// use linked_lists_rs::stack::Stack;
     /// let stack: Stack<i32> = Stack::new();
     /// ```
-    pub fn new() -> Self {
-        Stack(None)
+    pub const fn new() -> Self {
+        Stack {
+            head: None,
+            len: 0,
+            pool: None,
+        }
+    }
+
+    /// Creates a new [`Stack`] with a pool of `nodes` spare nodes allocated upfront
+    /// The first `nodes` pushes reuse them instead of allocating,
+    /// popped nodes are kept for reuse as long as the pool holds less than `nodes`
+    /// The [`Stack`] itself stays unbounded
+    ///
+    /// # Example
+    /// ```
+    /// use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::with_pool(16);
+    ///
+    /// // Reuses a preallocated node
+    /// stack.push(1);
+    /// assert_eq!(Some(1), stack.pop());
+    /// ```
+    pub fn with_pool(nodes: usize) -> Self {
+        let mut stack = Self::new();
+        stack.pool = Some(Box::new(NodePool {
+            slots: (0..nodes)
+                .map(|_| Box::new(MaybeUninit::uninit()))
+                .collect(),
+            max: nodes,
+        }));
+        stack
     }
 
     /// Push a new value on the top of the [`Stack`]
@@ -48,10 +128,11 @@ impl<T> Stack<T> {
     pub fn push(&mut self, value: T) {
         let new_node = Node {
             value,
-            next: mem::take(&mut self.0),
+            next: mem::take(&mut self.head),
         };
 
-        self.0 = Some(Box::new(new_node))
+        self.head = Some(NodePool::alloc(&mut self.pool, new_node));
+        self.len += 1;
     }
 
     /// Pops and return the value on the top of the [`Stack`]
@@ -68,11 +149,10 @@ impl<T> Stack<T> {
     /// assert_eq!(None, stack.pop());
     /// ```
     pub fn pop(&mut self) -> Option<T> {
-        let link = mem::take(&mut self.0);
-        link.map(|node| {
-            self.0 = node.next;
-            node.value
-        })
+        let mut node = mem::take(&mut self.head)?;
+        self.head = mem::take(&mut node.next);
+        self.len -= 1;
+        Some(NodePool::free(&mut self.pool, node))
     }
 
     /// Return a reference to the value on the top of the [`Stack`]
@@ -90,7 +170,7 @@ impl<T> Stack<T> {
     /// assert_eq!(None, stack.peek());
     /// ```
     pub fn peek(&self) -> Option<&T> {
-        let link = self.0.as_ref();
+        let link = self.head.as_ref();
         link.map(|node| &node.value)
     }
 
@@ -110,257 +190,2643 @@ impl<T> Stack<T> {
     /// assert_eq!(None, stack.peek_mut());
     /// ```
     pub fn peek_mut(&mut self) -> Option<&mut T> {
-        let link = self.0.as_mut();
+        let link = self.head.as_mut();
         link.map(|node| &mut node.value)
     }
-}
 
-// Custom code within the destructor.
-impl<T> Drop for Stack<T> {
-    fn drop(&mut self) {
-        let mut link = mem::take(&mut self.0);
-        while let Some(mut node) = link {
-            link = mem::take(&mut node.next);
-        }
+    /// Return the number of elements in the [`Stack`]
+    ///
+    /// # Overflow
+    /// The length is a tracked counter that never wraps around, every element lives
+    /// in its own heap node so the counter can't exceed `usize::MAX` before memory runs out
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::new();
+    ///
+    /// stack.push(5);
+    ///
+    /// assert_eq!(1, stack.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
     }
-}
 
-/// [`IntoIter`] struct for [`Stack`] consumed iteration
-/// Iterate from top to bottom
-pub struct IntoIter<T>(Stack<T>);
-
-impl<T> Iterator for IntoIter<T> {
-    type Item = T;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        self.0.pop()
+    /// Return the exact number of elements in the [`Stack`] as lower and upper bounds,
+    /// in the shape of [`Iterator::size_hint`] for iterators and collections built from it
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let stack = Stack::from([1, 2, 3]);
+    ///
+    /// assert_eq!((3, Some(3)), stack.capacity_hint());
+    /// ```
+    pub fn capacity_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
     }
-}
 
-impl<T> Stack<T> {
-    /// Iterator to the [`Stack`]
-    /// Consumes the data structure on iteration
+    /// Return `true` if the [`Stack`] contains no elements
     ///
     /// # Example
     /// ```
     /// # use linked_lists_rs::stack::Stack;
     /// let mut stack = Stack::new();
+    /// assert!(stack.is_empty());
     ///
-    /// // Insert values into the stack
-    /// for x in [1, 2, 3] {
-    ///     stack.push(x);
-    /// }
+    /// stack.push(5);
+    /// assert!(!stack.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Creates a new [`Stack`] from a [`Vec`]
+    /// Elements are pushed in order, so the last element of the [`Vec`] ends up on top
     ///
-    /// // Iterate the stack and verify its values
-    /// for (i, x) in std::iter::zip(stack, [3, 2, 1]) {
-    ///     assert_eq!(i, x);
-    /// }
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::from_vec(vec![1, 2, 3]);
     ///
+    /// assert_eq!(Some(3), stack.pop());
+    /// assert_eq!(Some(2), stack.pop());
+    /// assert_eq!(Some(1), stack.pop());
     /// ```
-    pub fn into_iter(self) -> IntoIter<T> {
-        IntoIter(self)
+    pub fn from_vec(vec: Vec<T>) -> Self {
+        let mut stack = Self::new();
+        for value in vec {
+            stack.push(value);
+        }
+        stack
     }
-}
-
-impl<T> IntoIterator for Stack<T> {
-    type Item = T;
-    type IntoIter = IntoIter<T>;
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.into_iter()
+    /// Consumes the [`Stack`] into a [`Vec`]
+    /// Elements are in top to bottom order, so the top of the [`Stack`] is the first element
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let stack = Stack::from_vec(vec![1, 2, 3]);
+    ///
+    /// assert_eq!(vec![3, 2, 1], stack.into_vec());
+    /// ```
+    pub fn into_vec(mut self) -> Vec<T> {
+        let mut vec = Vec::with_capacity(self.len);
+        while let Some(value) = self.pop() {
+            vec.push(value);
+        }
+        vec
     }
-}
-
-/// [`Iter`] struct for [`Stack`] referenced iteration
-/// Iterate from top to bottom
-pub struct Iter<'a, T>(Option<&'a Node<T>>);
-
-impl<'a, T> Iterator for Iter<'a, T> {
-    type Item = &'a T;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.0.take().map(|node| {
-            self.0 = node.next.as_deref();
-            &node.value
-        })
+    /// Return `true` if `other` is a prefix of the [`Stack`] comparing from top to bottom
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let stack = Stack::from_vec(vec![1, 2, 3]);
+    ///
+    /// assert!(stack.starts_with(&Stack::from_vec(vec![2, 3])));
+    /// assert!(!stack.starts_with(&Stack::from_vec(vec![1, 2])));
+    /// ```
+    pub fn starts_with(&self, other: &Stack<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        other.len <= self.len && self.iter().zip(other).all(|(a, b)| a == b)
     }
-}
-
-impl<'a, T> IntoIterator for &'a Stack<T> {
-    type Item = &'a T;
-    type IntoIter = Iter<'a, T>;
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
+    /// Return `true` if `other` is a suffix of the [`Stack`], i.e. matches its bottom portion
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let stack = Stack::from_vec(vec![1, 2, 3]);
+    ///
+    /// assert!(stack.ends_with(&Stack::from_vec(vec![1, 2])));
+    /// assert!(!stack.ends_with(&Stack::from_vec(vec![2, 3])));
+    /// ```
+    pub fn ends_with(&self, other: &Stack<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        other.len <= self.len
+            && self
+                .iter()
+                .skip(self.len - other.len)
+                .zip(other)
+                .all(|(a, b)| a == b)
     }
-}
 
-impl<T> Stack<T> {
-    /// Reference Iterator to the [`Stack`]
+    /// Retains only the elements specified by the predicate
+    /// Preserves the relative order of the retained elements
     ///
     /// # Example
     /// ```
     /// # use linked_lists_rs::stack::Stack;
-    /// let mut stack = Stack::new();
+    /// let mut stack = Stack::from_vec(vec![1, 2, 3, 4]);
     ///
-    /// // Insert values into the stack
-    /// for x in [1, 2, 3] {
-    ///     stack.push(x);
-    /// }
+    /// stack.retain(|x| x % 2 == 0);
     ///
-    /// // Use iter to iterate the stack and verify its values
-    /// for (i, x) in std::iter::zip(&stack, [3, 2, 1]) {
-    ///     assert_eq!(i, &x);
-    /// }
+    /// assert_eq!(vec![4, 2], stack.into_vec());
+    /// ```
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|value| f(value));
+    }
+
+    /// Retains only the elements specified by the predicate, from top to bottom,
+    /// passing a mutable reference so `f` can also update the retained elements
+    /// Preserves the relative order of the retained elements
     ///
-    /// // Stack is not consumed
-    /// assert_eq!(Some(&3), stack.peek());
+    /// # Example
     /// ```
-    pub fn iter(&self) -> Iter<'_, T> {
-        let node = self.0.as_deref();
-        Iter(node)
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::from_vec(vec![1, 2, 3]);
+    ///
+    /// stack.retain_mut(|ttl| {
+    ///     *ttl -= 1;
+    ///     *ttl > 0
+    /// });
+    ///
+    /// assert_eq!(vec![2, 1], stack.into_vec());
+    /// ```
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        let mut link = &mut self.head;
+        while let Some(mut node) = link.take() {
+            if f(&mut node.value) {
+                link = &mut link.insert(node).next;
+            } else {
+                *link = node.next;
+                self.len -= 1;
+            }
+        }
     }
-}
 
-/// [`IterMut`] struct for [`Stack`] mutable referenced iteration
-/// Iterate from top to bottom
-pub struct IterMut<'a, T>(Option<&'a mut Node<T>>);
+    /// Moves all elements of `other` into the [`Stack`] alternating nodes top to bottom,
+    /// starting with the top of `self`, the remainder of the longer one is kept at the bottom
+    /// Leaves `other` empty
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::from_vec(vec![1, 2, 3]);
+    /// let mut other = Stack::from_vec(vec![4, 5]);
+    ///
+    /// stack.interleave_with(&mut other);
+    ///
+    /// assert!(other.is_empty());
+    /// assert_eq!(vec![3, 5, 2, 4, 1], stack.into_vec());
+    /// ```
+    pub fn interleave_with(&mut self, other: &mut Stack<T>) {
+        let mut link = mem::take(&mut self.head);
+        let mut other_link = mem::take(&mut other.head);
+        let mut tail = &mut self.head;
+        while let Some(mut node) = link {
+            link = mem::take(&mut node.next);
+            tail = &mut tail.insert(node).next;
+            mem::swap(&mut link, &mut other_link);
+        }
+        *tail = other_link;
+        self.len += mem::take(&mut other.len);
+    }
 
-impl<'a, T> Iterator for IterMut<'a, T> {
-    type Item = &'a mut T;
+    /// Removes the run of elements equal to the top of the [`Stack`], keeping one
+    /// Stops at the first element that differs from the top
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::from_vec(vec![1, 2, 2, 2]);
+    ///
+    /// stack.dedup_top();
+    ///
+    /// assert_eq!(vec![2, 1], stack.into_vec());
+    /// ```
+    pub fn dedup_top(&mut self)
+    where
+        T: PartialEq,
+    {
+        if let Some(top) = self.head.as_mut() {
+            while let Some(mut node) = top.next.take() {
+                if node.value != top.value {
+                    top.next = Some(node);
+                    break;
+                }
+                top.next = mem::take(&mut node.next);
+                self.len -= 1;
+            }
+        }
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.0.take().map(|node| {
-            self.0 = node.next.as_deref_mut();
-            &mut node.value
-        })
+    /// Moves all elements of `other` to the bottom of the [`Stack`], leaving `other` empty
+    /// Walks to the bottom node of `self` so it runs in O(n) of `self`'s length
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::from_vec(vec![3, 4]);
+    /// let mut other = Stack::from_vec(vec![1, 2]);
+    ///
+    /// stack.append(&mut other);
+    ///
+    /// assert!(other.is_empty());
+    /// assert_eq!(vec![4, 3, 2, 1], stack.into_vec());
+    /// ```
+    pub fn append(&mut self, other: &mut Stack<T>) {
+        let mut link = &mut self.head;
+        while let Some(node) = link {
+            link = &mut node.next;
+        }
+        *link = mem::take(&mut other.head);
+        self.len += mem::take(&mut other.len);
     }
-}
 
-impl<'a, T> IntoIterator for &'a mut Stack<T> {
-    type Item = &'a mut T;
-    type IntoIter = IterMut<'a, T>;
+    /// Compares two stacks lexicographically starting from the bottom element upward,
+    /// so the oldest elements dominate the comparison
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// use std::cmp::Ordering;
+    ///
+    /// let stack = Stack::from_vec(vec![1, 5]);
+    /// let other = Stack::from_vec(vec![2, 0]);
+    ///
+    /// assert_eq!(Ordering::Less, stack.cmp_from_bottom(&other));
+    /// ```
+    pub fn cmp_from_bottom(&self, other: &Stack<T>) -> Ordering
+    where
+        T: Ord,
+    {
+        // Skip the extra top elements of the taller stack so both walks end at the bottom
+        // together, the deepest difference wins and equal prefixes fall back to the length.
+        let values = self.iter().skip(self.len.saturating_sub(other.len));
+        let other_values = other.iter().skip(other.len.saturating_sub(self.len));
+        values.zip(other_values).fold(
+            self.len.cmp(&other.len),
+            |ordering, (value, other_value)| match value.cmp(other_value) {
+                Ordering::Equal => ordering,
+                difference => difference,
+            },
+        )
+    }
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter_mut()
+    /// Removes up to `max` elements from the top of the [`Stack`] while they satisfy `pred`
+    /// Returns the removed elements as a new [`Stack`] preserving their order
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::from_vec(vec![1, 2, 3, 4]);
+    ///
+    /// let taken = stack.take_up_to(3, |x| *x > 2);
+    ///
+    /// assert_eq!(vec![4, 3], taken.into_vec());
+    /// assert_eq!(vec![2, 1], stack.into_vec());
+    /// ```
+    pub fn take_up_to<F: FnMut(&T) -> bool>(&mut self, max: usize, mut pred: F) -> Stack<T> {
+        let mut taken = Stack::new();
+        let mut count = 0;
+        let mut tail = &mut taken.head;
+        while count < max {
+            match mem::take(&mut self.head) {
+                Some(mut node) if pred(&node.value) => {
+                    self.head = mem::take(&mut node.next);
+                    tail = &mut tail.insert(node).next;
+                    count += 1;
+                }
+                link => {
+                    self.head = link;
+                    break;
+                }
+            }
+        }
+        self.len -= count;
+        taken.len = count;
+        taken
     }
-}
 
-impl<T> Stack<T> {
-    /// Mutable Reference Iterator to the [`Stack`]
+    /// Inserts a clone of `sep` between each pair of adjacent elements of the [`Stack`]
     ///
     /// # Example
     /// ```
     /// # use linked_lists_rs::stack::Stack;
-    /// let mut stack = Stack::new();
+    /// let mut stack = Stack::from_vec(vec![1, 2, 3]);
     ///
-    /// // Insert values into the stack
-    /// for x in [1, 2, 3] {
-    ///     stack.push(x);
-    /// }
+    /// stack.intersperse(0);
     ///
-    /// // Use iter_mut to iterate the stack and mutate it's values
-    /// for i in &mut stack {
-    ///     *i *= 2;
-    /// }
+    /// assert_eq!(vec![3, 0, 2, 0, 1], stack.into_vec());
+    /// ```
+    pub fn intersperse(&mut self, sep: T)
+    where
+        T: Clone,
+    {
+        let mut link = self.head.as_mut();
+        while let Some(node) = link {
+            let Some(next) = mem::take(&mut node.next) else {
+                break;
+            };
+            let sep_node = node.next.insert(NodePool::alloc(
+                &mut self.pool,
+                Node {
+                    value: sep.clone(),
+                    next: Some(next),
+                },
+            ));
+            self.len += 1;
+            link = sep_node.next.as_mut();
+        }
+    }
+
+    /// Returns the depth, from the top, of the first element for which `pred` is false
+    /// assuming the [`Stack`] is partitioned, like [`slice::partition_point`] but via a linear walk
     ///
-    /// // Assert values mutate as expected
-    /// for x in [6, 4, 2] {
-    ///     assert_eq!(Some(x), stack.pop());
-    /// }
+    /// # Example
     /// ```
-    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
-        let node = self.0.as_deref_mut();
-        IterMut(node)
+    /// # use linked_lists_rs::stack::Stack;
+    /// let stack = Stack::from_vec(vec![5, 4, 3, 2, 1]);
+    ///
+    /// assert_eq!(2, stack.partition_point(|x| *x < 3));
+    /// ```
+    pub fn partition_point<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        self.iter().take_while(|value| pred(value)).count()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::Stack;
+    /// Splits the [`Stack`] in two, keeping the top `at` elements
+    /// Returns a new [`Stack`] containing the rest in the same relative order
+    /// Returns an empty [`Stack`] if `at` is greater or equal to the length
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::from_vec(vec![1, 2, 3]);
+    ///
+    /// let rest = stack.split_off(1);
+    ///
+    /// assert_eq!(vec![3], stack.into_vec());
+    /// assert_eq!(vec![2, 1], rest.into_vec());
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Stack<T> {
+        let mut rest = Stack::new();
+        if at >= self.len {
+            return rest;
+        }
 
-    #[test]
-    fn creates_an_empty_list() {
-        let list: Stack<i32> = Stack::new();
-        assert_eq!(None, list.peek())
+        rest.head = mem::take(self.link_at(at));
+        rest.len = self.len - at;
+        self.len = at;
+        rest
     }
 
-    #[test]
-    fn pushes_element() {
-        let mut list = Stack::new();
-        let value = 5;
-        list.push(value);
-        assert_eq!(Some(&value), list.peek());
+    /// Consumes the [`Stack`] reducing its elements from top to bottom with the fallible `f`
+    /// Short-circuits on the first `Err`, returns `Ok(None)` if the [`Stack`] is empty
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let stack = Stack::from_vec(vec![1u8, 2, 3]);
+    /// assert_eq!(Ok(Some(6)), stack.try_reduce(|a, b| a.checked_add(b).ok_or("overflow")));
+    ///
+    /// let stack = Stack::from_vec(vec![1u8, 255]);
+    /// assert_eq!(Err("overflow"), stack.try_reduce(|a, b| a.checked_add(b).ok_or("overflow")));
+    /// ```
+    pub fn try_reduce<E, F: FnMut(T, T) -> Result<T, E>>(
+        mut self,
+        mut f: F,
+    ) -> Result<Option<T>, E> {
+        let Some(mut acc) = self.pop() else {
+            return Ok(None);
+        };
+        while let Some(value) = self.pop() {
+            acc = f(acc, value)?;
+        }
+        Ok(Some(acc))
+    }
+
+    /// Applies `f` to each element from top to bottom for side effects,
+    /// returning the unchanged [`Stack`] for chaining
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let stack = Stack::from_vec(vec![1, 2, 3]);
+    /// let mut sum = 0;
+    ///
+    /// let top = stack.tap(|x| sum += x).peek();
+    ///
+    /// assert_eq!(6, sum);
+    /// assert_eq!(Some(&3), top);
+    /// ```
+    pub fn tap<F: FnMut(&T)>(&self, f: F) -> &Self {
+        self.iter().for_each(f);
+        self
+    }
+
+    /// Returns a reference to the first element, from top to bottom, equal to one above it
+    /// Returns `None` if all the elements are unique
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let stack = Stack::from_vec(vec![1, 2, 3, 2, 1]);
+    ///
+    /// assert_eq!(Some(&2), stack.first_duplicate());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn first_duplicate(&self) -> Option<&T>
+    where
+        T: Eq + Hash,
+    {
+        let mut seen = HashSet::with_capacity(self.len);
+        self.iter().find(|value| !seen.insert(*value))
+    }
+
+    /// Return a reference to the value on the bottom of the [`Stack`], the oldest one
+    /// Returns `None` if the [`Stack`] is empty
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let stack = Stack::from_vec(vec![1, 2, 3]);
+    ///
+    /// assert_eq!(Some(&1), stack.last());
+    /// ```
+    pub fn last(&self) -> Option<&T> {
+        self.iter().last()
+    }
+
+    /// Return a mutable reference to the value on the bottom of the [`Stack`], the oldest one
+    /// Returns `None` if the [`Stack`] is empty
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::from_vec(vec![1, 2, 3]);
+    ///
+    /// if let Some(bottom) = stack.bottom_mut() {
+    ///     *bottom += 10;
+    /// }
+    ///
+    /// assert_eq!(vec![3, 2, 11], stack.into_vec());
+    /// ```
+    pub fn bottom_mut(&mut self) -> Option<&mut T> {
+        let depth = self.len.checked_sub(1)?;
+        self.link_at(depth).as_mut().map(|node| &mut node.value)
+    }
+
+    /// Pushes a clone of the value on the top of the [`Stack`]
+    /// Returns `false` if the [`Stack`] is empty
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::from([5]);
+    ///
+    /// assert!(stack.dup_top());
+    ///
+    /// assert_eq!(vec![5, 5], stack.into_vec());
+    /// ```
+    pub fn dup_top(&mut self) -> bool
+    where
+        T: Clone,
+    {
+        match self.peek().cloned() {
+            Some(value) => {
+                self.push(value);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Pushes each value of `iter` on the top of the [`Stack`] in order,
+    /// so the last yielded value ends up on top
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::from([1]);
+    ///
+    /// stack.push_iter(2..=3);
+    ///
+    /// assert_eq!(vec![3, 2, 1], stack.into_vec());
+    /// ```
+    pub fn push_iter<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+
+    /// Puts the elements of `other` on top of the [`Stack`] keeping their order,
+    /// so the top of `other` becomes the top of the [`Stack`]
+    /// No value is moved, but it walks to the bottom node of `other`
+    /// so it runs in O(n) of `other`'s length
+    /// An O(1) splice is not possible as the singly linked [`Stack`]
+    /// keeps no pointer to its bottom node
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::from([1, 2]);
+    ///
+    /// stack.prepend(Stack::from([3, 4]));
+    ///
+    /// assert_eq!(vec![4, 3, 2, 1], stack.into_vec());
+    /// ```
+    pub fn prepend(&mut self, mut other: Stack<T>) {
+        other.append(self);
+        self.head = mem::take(&mut other.head);
+        self.len = mem::take(&mut other.len);
+    }
+
+    /// Moves the first element, from the top, satisfying `pred` to the top of the [`Stack`]
+    /// keeping the order of the other elements
+    /// Returns `true` if a matching element was found, even if it already was the top
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::from([1, 2, 3]);
+    ///
+    /// assert!(stack.move_to_top(|x| *x == 1));
+    /// assert!(!stack.move_to_top(|x| *x == 4));
+    ///
+    /// assert_eq!(vec![1, 3, 2], stack.into_vec());
+    /// ```
+    pub fn move_to_top<F: FnMut(&T) -> bool>(&mut self, pred: F) -> bool {
+        let Some(depth) = self.iter().position(pred) else {
+            return false;
+        };
+
+        let link = self.link_at(depth);
+        if let Some(mut node) = mem::take(link) {
+            *link = mem::take(&mut node.next);
+            node.next = mem::take(&mut self.head);
+            self.head = Some(node);
+        }
+        true
+    }
+
+    /// Returns a reference to the last element, the deepest one, satisfying `pred`
+    /// Returns `None` if no element satisfies it
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let stack = Stack::from([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(Some(&2), stack.rfind(|x| x % 2 == 0));
+    /// ```
+    pub fn rfind<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Option<&T> {
+        self.iter().filter(|value| pred(value)).last()
+    }
+
+    /// Sorts the [`Stack`] so the smallest element ends up on top
+    /// The sort is stable and relinks the nodes without moving any value
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::from([3, 1, 2]);
+    ///
+    /// stack.sort();
+    ///
+    /// assert_eq!(vec![1, 2, 3], stack.into_vec());
+    /// ```
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    /// Sorts the [`Stack`] with the comparator `compare`, the smallest element ends up on top
+    /// The sort is stable, equal elements keep their order from the top
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::from([3, 1, 2]);
+    ///
+    /// stack.sort_by(|a, b| b.cmp(a));
+    ///
+    /// assert_eq!(vec![3, 2, 1], stack.into_vec());
+    /// ```
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        let head = mem::take(&mut self.head);
+        self.head = Self::merge_sort(head, self.len, &mut compare);
+    }
+
+    /// Sorts the [`Stack`] by the key extracted with `f`, the smallest key ends up on top
+    /// The sort is stable, elements with equal keys keep their order from the top
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::from([-3, 1, -2]);
+    ///
+    /// stack.sort_by_key(|x: &i32| x.abs());
+    ///
+    /// assert_eq!(vec![1, -2, -3], stack.into_vec());
+    /// ```
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Keeps the top `len` elements of the [`Stack`] and returns the removed ones,
+    /// from top to bottom
+    /// Returns an empty [`Vec`] if `len` is greater or equal to the length
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::from([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(vec![2, 1], stack.truncate_extract(2));
+    ///
+    /// assert_eq!(vec![4, 3], stack.into_vec());
+    /// ```
+    pub fn truncate_extract(&mut self, len: usize) -> Vec<T> {
+        self.split_off(len).into_vec()
+    }
+
+    /// Returns a mutable reference to the top of the [`Stack`],
+    /// pushing the value returned by `f` first if the [`Stack`] is empty
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::new();
+    ///
+    /// *stack.peek_or_push_with(|| 0) += 1;
+    /// *stack.peek_or_push_with(|| 0) += 1;
+    ///
+    /// assert_eq!(vec![2], stack.into_vec());
+    /// ```
+    pub fn peek_or_push_with<F: FnOnce() -> T>(&mut self, f: F) -> &mut T {
+        let len = &mut self.len;
+        let pool = &mut self.pool;
+        let node = self.head.get_or_insert_with(|| {
+            *len += 1;
+            NodePool::alloc(
+                pool,
+                Node {
+                    value: f(),
+                    next: None,
+                },
+            )
+        });
+        &mut node.value
+    }
+
+    /// Keeps the top `len` elements of the [`Stack`], dropping the rest
+    /// Does nothing if `len` is greater or equal to the length
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::from([1, 2, 3, 4]);
+    ///
+    /// stack.truncate(2);
+    ///
+    /// assert_eq!(vec![4, 3], stack.into_vec());
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        drop(self.split_off(len));
+    }
+
+    /// Splits the [`Stack`] into `parts` stacks of as equal as possible lengths,
+    /// the first ones being the longest
+    /// The first part holds the top of the [`Stack`] and every part keeps the order
+    /// If `parts` is greater than the length, the last parts are empty
+    ///
+    /// # Panics
+    /// Panics if `parts` is zero
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let stack = Stack::from([1, 2, 3, 4, 5]);
+    ///
+    /// let parts: Vec<_> = stack.split_into(2).into_iter().map(Stack::into_vec).collect();
+    ///
+    /// assert_eq!(vec![vec![5, 4, 3], vec![2, 1]], parts);
+    /// ```
+    pub fn split_into(mut self, parts: usize) -> Vec<Stack<T>> {
+        assert!(parts > 0, "cannot split a stack into zero parts");
+
+        let (size, extra) = (self.len / parts, self.len % parts);
+        let mut stacks = Vec::with_capacity(parts);
+        for i in 0..parts {
+            let rest = self.split_off(size + usize::from(i < extra));
+            stacks.push(mem::replace(&mut self, rest));
+        }
+        stacks
+    }
+
+    /// Repeatedly pops the top two elements and pushes back `f(below, top)`,
+    /// like applying an operator in Reverse Polish Notation, until one element remains
+    /// Returns that element leaving the [`Stack`] empty, or `None` if it was empty
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::from([10, 3, 2]);
+    ///
+    /// assert_eq!(Some(9), stack.reduce(|below, top| below - top));
+    /// assert!(stack.is_empty());
+    /// ```
+    pub fn reduce<F: FnMut(T, T) -> T>(&mut self, mut f: F) -> Option<T> {
+        let mut top = self.pop()?;
+        while let Some(below) = self.pop() {
+            top = f(below, top);
+        }
+        Some(top)
+    }
+
+    /// Returns a reference to the element with the largest key extracted with `f`
+    /// On ties the first one from the top wins, returns `None` if the [`Stack`] is empty
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let stack = Stack::from([-3, 1, 3]);
+    ///
+    /// assert_eq!(Some(&3), stack.max_by_key(|x: &i32| x.abs()));
+    /// ```
+    pub fn max_by_key<K: Ord, F: FnMut(&T) -> K>(&self, mut f: F) -> Option<&T> {
+        self.iter()
+            .map(|value| (f(value), value))
+            .reduce(|best, current| if current.0 > best.0 { current } else { best })
+            .map(|(_, value)| value)
+    }
+
+    /// Returns a reference to the element with the smallest key extracted with `f`
+    /// On ties the first one from the top wins, returns `None` if the [`Stack`] is empty
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let stack = Stack::from([-1, 2, 1]);
+    ///
+    /// assert_eq!(Some(&1), stack.min_by_key(|x: &i32| x.abs()));
+    /// ```
+    pub fn min_by_key<K: Ord, F: FnMut(&T) -> K>(&self, mut f: F) -> Option<&T> {
+        self.iter()
+            .map(|value| (f(value), value))
+            .reduce(|best, current| if current.0 < best.0 { current } else { best })
+            .map(|(_, value)| value)
+    }
+
+    /// Deals the elements of the [`Stack`] round-robin into `n` stacks, like dealing cards,
+    /// the `i`-th element from the top goes to the stack `i % n`
+    /// Every stack keeps the relative order of its elements
+    ///
+    /// # Panics
+    /// Panics if `n` is zero
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let stack = Stack::from([1, 2, 3, 4, 5]);
+    ///
+    /// let hands: Vec<_> = stack.deal(2).into_iter().map(Stack::into_vec).collect();
+    ///
+    /// assert_eq!(vec![vec![5, 3, 1], vec![4, 2]], hands);
+    /// ```
+    pub fn deal(mut self, n: usize) -> Vec<Stack<T>> {
+        assert!(n > 0, "cannot deal a stack into zero stacks");
+
+        // Dealing pushes onto each hand reversing it, so every hand is reversed back after
+        let mut hands: Vec<Stack<T>> = (0..n).map(|_| Stack::new()).collect();
+        for i in (0..n).cycle() {
+            let Some(node) = self.pop_node() else {
+                break;
+            };
+            hands[i].push_node(node);
+        }
+        for hand in &mut hands {
+            hand.reverse_nodes();
+        }
+        hands
+    }
+
+    /// Same as [`Stack::prepend`]
+    pub fn stack_on_top(&mut self, other: Stack<T>) {
+        self.prepend(other);
+    }
+
+    /// Replaces every element equal to `from` with a clone of `to`, in place
+    /// Returns the number of replaced elements
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::from([1, 2, 1, 3]);
+    ///
+    /// assert_eq!(2, stack.replace_all(&1, 0));
+    ///
+    /// assert_eq!(vec![3, 0, 2, 0], stack.into_vec());
+    /// ```
+    pub fn replace_all(&mut self, from: &T, to: T) -> usize
+    where
+        T: PartialEq + Clone,
+    {
+        let mut count = 0;
+        for value in self.iter_mut().filter(|value| *value == from) {
+            *value = to.clone();
+            count += 1;
+        }
+        count
+    }
+
+    /// Returns the depth, from the top, of the first element satisfying `pred`
+    /// Returns `None` if no element satisfies it
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let stack = Stack::from([1, 2, 3]);
+    ///
+    /// assert_eq!(Some(2), stack.position(|x| *x == 1));
+    /// assert_eq!(None, stack.position(|x| *x == 4));
+    /// ```
+    pub fn position<F: FnMut(&T) -> bool>(&self, pred: F) -> Option<usize> {
+        self.iter().position(pred)
+    }
+
+    /// Rotates the values of the [`Stack`] so each one moves `shift` slots deeper,
+    /// the deepest values wrapping around to the top
+    /// The nodes are kept in place, only the values are cloned into their new slots
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::from([1, 2, 3, 4]);
+    ///
+    /// stack.cycle_values(1);
+    ///
+    /// assert_eq!(vec![1, 4, 3, 2], stack.into_vec());
+    /// ```
+    pub fn cycle_values(&mut self, shift: usize)
+    where
+        T: Clone,
+    {
+        if self.len == 0 {
+            return;
+        }
+
+        let mut values: Vec<T> = self.iter().cloned().collect();
+        values.rotate_right(shift % self.len);
+        for (slot, value) in self.iter_mut().zip(values) {
+            *slot = value;
+        }
+    }
+
+    /// Return `true` if the [`Stack`] holds the same elements as `slice`, from top to bottom
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let stack = Stack::from([1, 2, 3]);
+    ///
+    /// assert!(stack.eq_slice(&[3, 2, 1]));
+    /// assert!(!stack.eq_slice(&[1, 2, 3]));
+    /// ```
+    pub fn eq_slice(&self, slice: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.len == slice.len() && self.iter().eq(slice)
+    }
+
+    /// Consumes the [`Stack`] yielding its values from bottom to top,
+    /// the first pushed value comes first
+    /// The nodes are relinked in reverse order upfront, without moving any value
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::new();
+    ///
+    /// stack.push(1);
+    /// stack.push(2);
+    /// stack.push(3);
+    ///
+    /// assert_eq!(vec![1, 2, 3], stack.into_iter_rev().collect::<Vec<_>>());
+    /// ```
+    pub fn into_iter_rev(mut self) -> impl Iterator<Item = T> {
+        self.reverse_nodes();
+        self.into_iter()
+    }
+
+    /// Removes the elements satisfying `pred` and returns them, from top to bottom,
+    /// the inverse of [`Stack::retain_mut`]
+    /// Preserves the relative order of the remaining elements
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::from([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(vec![4, 2], stack.drain_filter(|x| *x % 2 == 0));
+    ///
+    /// assert_eq!(vec![3, 1], stack.into_vec());
+    /// ```
+    pub fn drain_filter<F: FnMut(&mut T) -> bool>(&mut self, mut pred: F) -> Vec<T> {
+        let mut removed = Vec::new();
+        let mut link = &mut self.head;
+        while let Some(mut node) = link.take() {
+            if pred(&mut node.value) {
+                let Node { value, next } = *node;
+                *link = next;
+                removed.push(value);
+                self.len -= 1;
+            } else {
+                link = &mut link.insert(node).next;
+            }
+        }
+        removed
+    }
+
+    /// Removes the repeated elements keeping the first occurrence of each value
+    /// scanning from the bottom, so the deepest copy survives
+    /// Preserves the relative order of the remaining elements
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::from([1, 2, 1, 3, 2]);
+    ///
+    /// stack.unique_from_bottom();
+    ///
+    /// assert_eq!(vec![3, 2, 1], stack.into_vec());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn unique_from_bottom(&mut self)
+    where
+        T: Eq + Hash + Clone,
+    {
+        // The deepest copy of a value is the last one met from the top
+        let mut remaining: HashMap<T, usize> = HashMap::with_capacity(self.len);
+        for value in self.iter() {
+            *remaining.entry(value.clone()).or_default() += 1;
+        }
+        self.retain(|value| match remaining.get_mut(value) {
+            Some(count) => {
+                *count -= 1;
+                *count == 0
+            }
+            None => true,
+        });
+    }
+
+    // Returns the link holding the node at `depth` from the top,
+    // or the bottom `None` link if `depth` is greater or equal to the length.
+    fn link_at(&mut self, depth: usize) -> &mut Link<T> {
+        let mut link = &mut self.head;
+        for _ in 0..depth {
+            if let Some(node) = link {
+                link = &mut node.next;
+            }
+        }
+        link
+    }
+
+    // Detaches the top node, keeping `len` consistent.
+    fn pop_node(&mut self) -> Link<T> {
+        let mut node = self.head.take()?;
+        self.head = node.next.take();
+        self.len -= 1;
+        Some(node)
+    }
+
+    // Links a detached `node` on the top, keeping `len` consistent.
+    fn push_node(&mut self, mut node: Box<Node<T>>) {
+        node.next = self.head.take();
+        self.head = Some(node);
+        self.len += 1;
+    }
+
+    // Reverses the order of the nodes by relinking them, without moving any value.
+    fn reverse_nodes(&mut self) {
+        let mut link = mem::take(&mut self.head);
+        self.len = 0;
+        while let Some(mut node) = link {
+            link = mem::take(&mut node.next);
+            self.push_node(node);
+        }
+    }
+
+    // Sorts the `len` nodes of the chain starting at `head` by splitting it in half
+    // and merging both sorted halves back together.
+    fn merge_sort<F: FnMut(&T, &T) -> Ordering>(
+        mut head: Link<T>,
+        len: usize,
+        compare: &mut F,
+    ) -> Link<T> {
+        if len < 2 {
+            return head;
+        }
+
+        let mid = len / 2;
+        let mut link = &mut head;
+        for _ in 0..mid {
+            if let Some(node) = link {
+                link = &mut node.next;
+            }
+        }
+        let back = link.take();
+
+        let front = Self::merge_sort(head, mid, compare);
+        let back = Self::merge_sort(back, len - mid, compare);
+        Self::merge(front, back, compare)
+    }
+
+    // Merges two sorted chains, taking from `front` on ties to keep the sort stable.
+    fn merge<F: FnMut(&T, &T) -> Ordering>(
+        mut front: Link<T>,
+        mut back: Link<T>,
+        compare: &mut F,
+    ) -> Link<T> {
+        let mut head = None;
+        let mut tail = &mut head;
+        while let (Some(a), Some(b)) = (&front, &back) {
+            let source = if compare(&b.value, &a.value) == Ordering::Less {
+                &mut back
+            } else {
+                &mut front
+            };
+            if let Some(mut node) = source.take() {
+                *source = node.next.take();
+                tail = &mut tail.insert(node).next;
+            }
+        }
+        *tail = front.or(back);
+        head
+    }
+}
+
+impl Stack<char> {
+    /// Consumes the [`Stack`] into a [`String`] of its characters from top to bottom,
+    /// so the last pushed character comes first
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let stack: Stack<char> = "abc".chars().collect();
+    ///
+    /// assert_eq!("cba", stack.into_string());
+    /// ```
+    pub fn into_string(self) -> String {
+        self.into_iter().collect()
+    }
+}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Lists the values from top to bottom, leaving out the nodes and the pool.
+impl<T: fmt::Debug> fmt::Debug for Stack<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Stack ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for Stack<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other)
+    }
+}
+
+impl<T: Eq> Eq for Stack<T> {}
+
+// Lexicographic order in iteration order, matching `Vec` semantics.
+impl<T: PartialOrd> PartialOrd for Stack<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other)
+    }
+}
+
+impl<T: Ord> Ord for Stack<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other)
+    }
+}
+
+// Pushes the array elements in order, the last element of the array ends up on top.
+impl<T, const N: usize> From<[T; N]> for Stack<T> {
+    fn from(values: [T; N]) -> Self {
+        let mut stack = Self::new();
+        for value in values {
+            stack.push(value);
+        }
+        stack
+    }
+}
+
+// Values are pushed in order, so the last yielded value ends up on top.
+impl<T> FromIterator<T> for Stack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stack = Self::new();
+        stack.push_iter(iter);
+        stack
+    }
+}
+
+// Values are pushed in order, so the last yielded value ends up on top.
+impl<T> Extend<T> for Stack<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.push_iter(iter);
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for Stack<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.push_iter(iter.into_iter().copied());
+    }
+}
+
+// Custom code within the destructor.
+impl<T> Drop for Stack<T> {
+    fn drop(&mut self) {
+        let mut link = mem::take(&mut self.head);
+        while let Some(mut node) = link {
+            link = mem::take(&mut node.next);
+        }
+    }
+}
+
+/// [`IntoIter`] struct for [`Stack`] consumed iteration
+/// Iterate from top to bottom
+pub struct IntoIter<T>(Stack<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.capacity_hint()
+    }
+
+    fn count(self) -> usize {
+        self.0.len
+    }
+}
+
+impl<T> IntoIterator for Stack<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Iterator to the [`Stack`]
+    /// Consumes the data structure on iteration
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::new();
+    ///
+    /// // Insert values into the stack
+    /// for x in [1, 2, 3] {
+    ///     stack.push(x);
+    /// }
+    ///
+    /// // Iterate the stack and verify its values
+    /// for (i, x) in std::iter::zip(stack, [3, 2, 1]) {
+    ///     assert_eq!(i, x);
+    /// }
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+/// [`Iter`] struct for [`Stack`] referenced iteration
+/// Iterate from top to bottom
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.take().map(|node| {
+            self.next = node.next.as_deref();
+            self.len -= 1;
+            &node.value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn count(self) -> usize {
+        self.len
+    }
+}
+
+// Manual impl as deriving would needlessly require `T: Clone`.
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter {
+            next: self.next,
+            len: self.len,
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Stack<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> Stack<T> {
+    /// Reference Iterator to the [`Stack`]
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::new();
+    ///
+    /// // Insert values into the stack
+    /// for x in [1, 2, 3] {
+    ///     stack.push(x);
+    /// }
+    ///
+    /// // Use iter to iterate the stack and verify its values
+    /// for (i, x) in std::iter::zip(&stack, [3, 2, 1]) {
+    ///     assert_eq!(i, &x);
+    /// }
+    ///
+    /// // Stack is not consumed
+    /// assert_eq!(Some(&3), stack.peek());
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+            len: self.len,
+        }
+    }
+}
+
+/// [`IterMut`] struct for [`Stack`] mutable referenced iteration
+/// Iterate from top to bottom
+pub struct IterMut<'a, T> {
+    next: Option<&'a mut Node<T>>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.take().map(|node| {
+            self.next = node.next.as_deref_mut();
+            self.len -= 1;
+            &mut node.value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn count(self) -> usize {
+        self.len
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Stack<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> Stack<T> {
+    /// Mutable Reference Iterator to the [`Stack`]
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::new();
+    ///
+    /// // Insert values into the stack
+    /// for x in [1, 2, 3] {
+    ///     stack.push(x);
+    /// }
+    ///
+    /// // Use iter_mut to iterate the stack and mutate it's values
+    /// for i in &mut stack {
+    ///     *i *= 2;
+    /// }
+    ///
+    /// // Assert values mutate as expected
+    /// for x in [6, 4, 2] {
+    ///     assert_eq!(Some(x), stack.pop());
+    /// }
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            next: self.head.as_deref_mut(),
+            len: self.len,
+        }
+    }
+}
+
+/// [`Drain`] struct for [`Stack`] draining iteration
+/// Iterate from top to bottom, removing the remaining elements when dropped
+pub struct Drain<'a, T>(&'a mut Stack<T>);
+
+impl<'a, T> Iterator for Drain<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.capacity_hint()
+    }
+
+    fn count(self) -> usize {
+        self.0.len
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Drain<'a, T> {}
+
+impl<'a, T> Drop for Drain<'a, T> {
+    fn drop(&mut self) {
+        while self.0.pop().is_some() {}
+    }
+}
+
+impl<T> Stack<T> {
+    /// Draining Iterator to the [`Stack`]
+    /// Removes the elements on iteration but, unlike `into_iter`, keeps the [`Stack`] usable
+    /// Elements not consumed are removed when the [`Drain`] is dropped
+    ///
+    /// # Example
+    /// ```
+    /// # use linked_lists_rs::stack::Stack;
+    /// let mut stack = Stack::from([1, 2, 3]);
+    ///
+    /// // Take only the top element, the rest is dropped with the iterator
+    /// assert_eq!(Some(3), stack.drain().next());
+    /// assert!(stack.is_empty());
+    ///
+    /// // Stack can be reused
+    /// stack.push(4);
+    /// assert_eq!(Some(4), stack.pop());
+    /// ```
+    pub fn drain(&mut self) -> Drain<'_, T> {
+        Drain(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Stack;
+    use crate::counting_allocator::allocations;
+    use std::cmp::Ordering;
+    use std::mem;
+
+    #[test]
+    fn creates_an_empty_list() {
+        let list: Stack<i32> = Stack::new();
+        assert_eq!(None, list.peek())
+    }
+
+    #[test]
+    fn pushes_element() {
+        let mut list = Stack::new();
+        let value = 5;
+        list.push(value);
+        assert_eq!(Some(&value), list.peek());
+    }
+
+    #[test]
+    fn pops_empty() {
+        let mut list: Stack<i32> = Stack::new();
+        assert_eq!(None, list.pop())
+    }
+
+    #[test]
+    fn pops() {
+        let mut list = Stack::new();
+        let value = 5;
+        list.push(value);
+        assert_eq!(Some(value), list.pop());
+    }
+
+    #[test]
+    fn peek() {
+        let mut list = Stack::new();
+        assert_eq!(list.peek(), None);
+        assert_eq!(list.peek_mut(), None);
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        assert_eq!(list.peek(), Some(&3));
+        assert_eq!(list.peek_mut(), Some(&mut 3));
+        list.peek_mut().map(|value| *value = 42);
+
+        assert_eq!(list.peek(), Some(&42));
+        assert_eq!(list.pop(), Some(42));
+    }
+
+    #[test]
+    fn into_iter() {
+        let mut list = Stack::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn iter() {
+        let mut list = Stack::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&1));
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut list = Stack::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        let mut iter = list.iter_mut();
+        assert_eq!(iter.next(), Some(&mut 3));
+        assert_eq!(iter.next(), Some(&mut 2));
+        assert_eq!(iter.next(), Some(&mut 1));
+    }
+
+    #[test]
+    fn len() {
+        let mut list = Stack::new();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+
+        list.push(1);
+        list.push(2);
+        assert_eq!(list.len(), 2);
+
+        list.pop();
+        assert_eq!(list.len(), 1);
+        list.pop();
+        list.pop();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn from_vec() {
+        let mut list = Stack::from_vec(vec![1, 2, 3]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn into_vec() {
+        let mut list = Stack::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+        assert_eq!(list.into_vec(), vec![3, 2, 1]);
+
+        let list: Stack<i32> = Stack::new();
+        assert_eq!(list.into_vec(), vec![]);
+    }
+
+    #[test]
+    fn vec_round_trip() {
+        let v = vec![1, 2, 3, 4];
+
+        // A round trip reverses the original order
+        let reversed = Stack::from_vec(v.clone()).into_vec();
+        assert_eq!(reversed, v.iter().rev().copied().collect::<Vec<_>>());
+
+        // Two round trips restore it
+        assert_eq!(Stack::from_vec(reversed).into_vec(), v);
+    }
+
+    #[test]
+    fn starts_with() {
+        let list = Stack::from_vec(vec![1, 2, 3, 4]);
+
+        assert!(list.starts_with(&Stack::new()));
+        assert!(list.starts_with(&Stack::from_vec(vec![4])));
+        assert!(list.starts_with(&Stack::from_vec(vec![3, 4])));
+        assert!(list.starts_with(&Stack::from_vec(vec![1, 2, 3, 4])));
+
+        assert!(!list.starts_with(&Stack::from_vec(vec![3])));
+        assert!(!list.starts_with(&Stack::from_vec(vec![1, 2])));
+        assert!(!list.starts_with(&Stack::from_vec(vec![0, 1, 2, 3, 4])));
+    }
+
+    #[test]
+    fn ends_with() {
+        let list = Stack::from_vec(vec![1, 2, 3, 4]);
+
+        assert!(list.ends_with(&Stack::new()));
+        assert!(list.ends_with(&Stack::from_vec(vec![1])));
+        assert!(list.ends_with(&Stack::from_vec(vec![1, 2])));
+        assert!(list.ends_with(&Stack::from_vec(vec![1, 2, 3, 4])));
+
+        assert!(!list.ends_with(&Stack::from_vec(vec![2])));
+        assert!(!list.ends_with(&Stack::from_vec(vec![3, 4])));
+        assert!(!list.ends_with(&Stack::from_vec(vec![0, 1, 2, 3, 4])));
+    }
+
+    #[test]
+    fn retain() {
+        let mut list = Stack::from_vec(vec![1, 2, 3, 4, 5, 6]);
+        list.retain(|_| true);
+        assert_eq!(list.len(), 6);
+
+        list.retain(|x| x % 2 == 0);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.into_vec(), vec![6, 4, 2]);
+
+        let mut list = Stack::from_vec(vec![1, 2, 3]);
+        list.retain(|_| false);
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn interleave_with_equal_lengths() {
+        let mut list = Stack::from_vec(vec![1, 2, 3]);
+        let mut other = Stack::from_vec(vec![4, 5, 6]);
+
+        list.interleave_with(&mut other);
+        assert_eq!(list.len(), 6);
+        assert_eq!(other.len(), 0);
+        assert_eq!(other.pop(), None);
+        assert_eq!(list.into_vec(), vec![3, 6, 2, 5, 1, 4]);
+    }
+
+    #[test]
+    fn interleave_with_unequal_lengths() {
+        let mut list = Stack::from_vec(vec![1]);
+        let mut other = Stack::from_vec(vec![4, 5, 6]);
+
+        list.interleave_with(&mut other);
+        assert_eq!(list.len(), 4);
+        assert!(other.is_empty());
+        assert_eq!(list.into_vec(), vec![1, 6, 5, 4]);
+
+        let mut list = Stack::from_vec(vec![1, 2, 3]);
+        let mut other = Stack::new();
+
+        list.interleave_with(&mut other);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.into_vec(), vec![3, 2, 1]);
+
+        let mut list = Stack::new();
+        let mut other = Stack::from_vec(vec![1, 2]);
+
+        list.interleave_with(&mut other);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.into_vec(), vec![2, 1]);
+    }
+
+    #[test]
+    fn const_new() {
+        static STACK: Stack<i32> = Stack::new();
+        assert!(STACK.is_empty());
+        assert_eq!(STACK.peek(), None);
+    }
+
+    #[test]
+    fn dedup_top() {
+        let mut list = Stack::from_vec(vec![2, 1, 1, 3, 3, 3]);
+        list.dedup_top();
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.into_vec(), vec![3, 1, 1, 2]);
+
+        let mut list = Stack::from_vec(vec![3, 3]);
+        list.dedup_top();
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.into_vec(), vec![3]);
+    }
+
+    #[test]
+    fn dedup_top_without_duplicates() {
+        let mut list = Stack::from_vec(vec![1, 1, 2]);
+        list.dedup_top();
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.into_vec(), vec![2, 1, 1]);
+
+        let mut list: Stack<i32> = Stack::new();
+        list.dedup_top();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn append() {
+        let mut list = Stack::from_vec(vec![3, 4]);
+        let mut other = Stack::from_vec(vec![1, 2]);
+
+        list.append(&mut other);
+        assert_eq!(list.len(), 4);
+        assert_eq!(other.len(), 0);
+        assert_eq!(other.pop(), None);
+        assert_eq!(list.into_vec(), vec![4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn append_onto_empty() {
+        let mut list = Stack::new();
+        let mut other = Stack::from_vec(vec![1, 2]);
+
+        list.append(&mut other);
+        assert_eq!(list.len(), 2);
+        assert!(other.is_empty());
+        assert_eq!(list.into_vec(), vec![2, 1]);
+    }
+
+    #[test]
+    fn append_empty() {
+        let mut list = Stack::from_vec(vec![1, 2]);
+        let mut other = Stack::new();
+
+        list.append(&mut other);
+        assert_eq!(list.len(), 2);
+        assert!(other.is_empty());
+
+        // Stack is still usable from both ends
+        let mut other = Stack::from_vec(vec![0]);
+        list.append(&mut other);
+        assert_eq!(list.into_vec(), vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn cmp_from_bottom_prefix() {
+        let list = Stack::from_vec(vec![1, 2]);
+
+        assert_eq!(
+            list.cmp_from_bottom(&Stack::from_vec(vec![1, 2])),
+            Ordering::Equal
+        );
+        assert_eq!(
+            list.cmp_from_bottom(&Stack::from_vec(vec![1])),
+            Ordering::Greater
+        );
+        assert_eq!(
+            list.cmp_from_bottom(&Stack::from_vec(vec![1, 2, 3])),
+            Ordering::Less
+        );
+        assert_eq!(list.cmp_from_bottom(&Stack::new()), Ordering::Greater);
+        assert_eq!(
+            Stack::<i32>::new().cmp_from_bottom(&Stack::new()),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn cmp_from_bottom_elements() {
+        let list = Stack::from_vec(vec![1, 9, 9]);
+
+        assert_eq!(
+            list.cmp_from_bottom(&Stack::from_vec(vec![2, 0])),
+            Ordering::Less
+        );
+        assert_eq!(
+            list.cmp_from_bottom(&Stack::from_vec(vec![1, 9, 8])),
+            Ordering::Greater
+        );
+        assert_eq!(
+            list.cmp_from_bottom(&Stack::from_vec(vec![0, 9, 9, 9])),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn cmp_from_bottom_matches_slices() {
+        let values = [
+            vec![],
+            vec![1],
+            vec![2],
+            vec![1, 1],
+            vec![1, 2],
+            vec![2, 1],
+            vec![1, 2, 1],
+        ];
+        for a in &values {
+            for b in &values {
+                let (list, other) = (Stack::from_vec(a.clone()), Stack::from_vec(b.clone()));
+                let before = allocations();
+                assert_eq!(list.cmp_from_bottom(&other), a.cmp(b), "{a:?} {b:?}");
+                assert_eq!(allocations(), before);
+            }
+        }
+    }
+
+    #[test]
+    fn eq() {
+        assert_eq!(Stack::from_vec(vec![1, 2]), Stack::from_vec(vec![1, 2]));
+        assert_ne!(Stack::from_vec(vec![1, 2]), Stack::from_vec(vec![2, 1]));
+        assert_ne!(Stack::from_vec(vec![1, 2]), Stack::from_vec(vec![2]));
+        assert_eq!(Stack::<i32>::new(), Stack::new());
+    }
+
+    #[test]
+    fn ord_prefix() {
+        // Compares top to bottom, a prefix is less
+        assert!(Stack::from_vec(vec![2]) < Stack::from_vec(vec![1, 2]));
+        assert!(Stack::new() < Stack::from_vec(vec![1]));
+        assert_eq!(
+            Stack::from_vec(vec![1, 2]).cmp(&Stack::from_vec(vec![1, 2])),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn ord_elements() {
+        assert!(Stack::from_vec(vec![9, 1]) < Stack::from_vec(vec![0, 2]));
+        assert!(Stack::from_vec(vec![1, 3]) > Stack::from_vec(vec![2, 2, 2]));
+
+        let mut stacks = vec![
+            Stack::from_vec(vec![3]),
+            Stack::from_vec(vec![1, 2]),
+            Stack::from_vec(vec![2]),
+        ];
+        stacks.sort();
+        assert_eq!(
+            stacks,
+            vec![
+                Stack::from_vec(vec![2]),
+                Stack::from_vec(vec![1, 2]),
+                Stack::from_vec(vec![3]),
+            ]
+        );
+    }
+
+    #[test]
+    fn take_up_to_limit() {
+        let mut list = Stack::from_vec(vec![1, 2, 3, 4]);
+        let taken = list.take_up_to(2, |_| true);
+        assert_eq!(taken.len(), 2);
+        assert_eq!(list.len(), 2);
+        assert_eq!(taken.into_vec(), vec![4, 3]);
+        assert_eq!(list.into_vec(), vec![2, 1]);
+    }
+
+    #[test]
+    fn take_up_to_predicate() {
+        let mut list = Stack::from_vec(vec![1, 4, 2, 3]);
+        let taken = list.take_up_to(10, |x| *x < 4);
+        assert_eq!(taken, Stack::from_vec(vec![2, 3]));
+        assert_eq!(list, Stack::from_vec(vec![1, 4]));
+
+        let taken = list.take_up_to(10, |x| *x < 4);
+        assert!(taken.is_empty());
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn take_up_to_fewer_available() {
+        let mut list = Stack::from_vec(vec![1, 2]);
+        let taken = list.take_up_to(5, |_| true);
+        assert_eq!(taken.len(), 2);
+        assert_eq!(taken.into_vec(), vec![2, 1]);
+        assert!(list.is_empty());
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn size_hint() {
+        let n = 5;
+        let mut list = Stack::new();
+        for x in 0..n {
+            list.push(x);
+        }
+
+        let mut iter = list.iter();
+        assert_eq!(iter.size_hint(), (n, Some(n)));
+        iter.next();
+        assert_eq!(iter.size_hint(), (n - 1, Some(n - 1)));
+
+        let mut iter = list.iter_mut();
+        assert_eq!(iter.size_hint(), (n, Some(n)));
+        iter.next();
+        assert_eq!(iter.size_hint(), (n - 1, Some(n - 1)));
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.size_hint(), (n, Some(n)));
+        iter.by_ref().for_each(drop);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn intersperse() {
+        let mut list = Stack::from_vec(vec![1, 2, 3]);
+        list.intersperse(0);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(0));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(0));
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), None);
+
+        let mut list = Stack::from_vec(vec![1]);
+        list.intersperse(0);
+        assert_eq!(list, Stack::from_vec(vec![1]));
+
+        let mut list = Stack::new();
+        list.intersperse(0);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn partition_point() {
+        let list = Stack::from_vec(vec![5, 4, 3, 2, 1]);
+        assert_eq!(list.partition_point(|_| true), 5);
+        assert_eq!(list.partition_point(|_| false), 0);
+        assert_eq!(list.partition_point(|x| *x < 4), 3);
+
+        let list: Stack<i32> = Stack::new();
+        assert_eq!(list.partition_point(|_| true), 0);
+    }
+
+    #[test]
+    fn split_off() {
+        let mut list = Stack::from_vec(vec![1, 2, 3, 4]);
+        let rest = list.split_off(2);
+        assert_eq!(list.len(), 2);
+        assert_eq!(rest.len(), 2);
+        assert_eq!(list.into_vec(), vec![4, 3]);
+        assert_eq!(rest.into_vec(), vec![2, 1]);
+    }
+
+    #[test]
+    fn split_off_boundaries() {
+        let mut list = Stack::from_vec(vec![1, 2, 3]);
+        let rest = list.split_off(0);
+        assert!(list.is_empty());
+        assert_eq!(list.pop(), None);
+        assert_eq!(rest.len(), 3);
+        assert_eq!(rest.into_vec(), vec![3, 2, 1]);
+
+        let mut list = Stack::from_vec(vec![1, 2, 3]);
+        let rest = list.split_off(3);
+        assert!(rest.is_empty());
+        assert_eq!(list.len(), 3);
+
+        let rest = list.split_off(10);
+        assert!(rest.is_empty());
+        assert_eq!(list.into_vec(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn try_reduce() {
+        let list = Stack::from_vec(vec![1, 2, 3, 4]);
+        let result: Result<_, ()> = list.try_reduce(|a, b| Ok(a + b));
+        assert_eq!(result, Ok(Some(10)));
+
+        // Reduces top to bottom
+        let list = Stack::from_vec(vec![vec![1], vec![2], vec![3]]);
+        let result: Result<_, ()> = list.try_reduce(|a, b| Ok([a, b].concat()));
+        assert_eq!(result, Ok(Some(vec![3, 2, 1])));
+    }
+
+    #[test]
+    fn try_reduce_error() {
+        let mut calls = 0;
+        let list = Stack::from_vec(vec![1u8, 200, 100, 1]);
+        let result = list.try_reduce(|a, b| {
+            calls += 1;
+            a.checked_add(b).ok_or(calls)
+        });
+        assert_eq!(result, Err(2));
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn try_reduce_empty() {
+        let list: Stack<i32> = Stack::new();
+        let result: Result<_, ()> = list.try_reduce(|a, b| Ok(a + b));
+        assert_eq!(result, Ok(None));
     }
 
     #[test]
-    fn pops_empty() {
+    fn tap() {
+        let list = Stack::from_vec(vec![1, 2, 3]);
+        let mut buffer = Vec::new();
+
+        let len = list.tap(|x| buffer.push(*x)).len();
+        assert_eq!(buffer, vec![3, 2, 1]);
+        assert_eq!(len, 3);
+        assert_eq!(list, Stack::from_vec(vec![1, 2, 3]));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn first_duplicate() {
+        assert_eq!(
+            Stack::from_vec(vec![2, 3, 1, 1]).first_duplicate(),
+            Some(&1)
+        );
+        assert_eq!(
+            Stack::from_vec(vec![2, 4, 3, 1, 2]).first_duplicate(),
+            Some(&2)
+        );
+        assert_eq!(Stack::from_vec(vec![1, 2, 3]).first_duplicate(), None);
+        assert_eq!(Stack::<i32>::new().first_duplicate(), None);
+    }
+
+    #[test]
+    fn last() {
+        let list = Stack::from_vec(vec![1, 2, 3]);
+        assert_eq!(list.last(), Some(&1));
+        assert_eq!(list.peek(), Some(&3));
+
+        let list: Stack<i32> = Stack::new();
+        assert_eq!(list.last(), None);
+    }
+
+    #[test]
+    fn from_array() {
+        let list: Stack<i32> = Stack::from([]);
+        assert!(list.is_empty());
+
+        let list = Stack::from([1]);
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.into_vec(), vec![1]);
+
+        let list = Stack::from([1, 2, 3]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.into_vec(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn dup_top() {
+        let mut list = Stack::from([1, 2]);
+        assert!(list.dup_top());
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.into_vec(), vec![2, 2, 1]);
+
         let mut list: Stack<i32> = Stack::new();
-        assert_eq!(None, list.pop())
+        assert!(!list.dup_top());
+        assert!(list.is_empty());
     }
 
     #[test]
-    fn pops() {
+    fn push_iter() {
         let mut list = Stack::new();
-        let value = 5;
-        list.push(value);
-        assert_eq!(Some(value), list.pop());
+        list.push_iter(vec![1, 2, 3]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.into_vec(), vec![3, 2, 1]);
     }
 
     #[test]
-    fn peek() {
+    fn prepend() {
+        let mut list = Stack::from([1, 2]);
+        let mut donor = Stack::from([3, 4]);
+        list.prepend(mem::take(&mut donor));
+        assert!(donor.is_empty());
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.into_vec(), vec![4, 3, 2, 1]);
+
         let mut list = Stack::new();
-        assert_eq!(list.peek(), None);
-        assert_eq!(list.peek_mut(), None);
-        list.push(1);
-        list.push(2);
-        list.push(3);
+        list.prepend(Stack::from([1, 2]));
+        assert_eq!(list.into_vec(), vec![2, 1]);
 
-        assert_eq!(list.peek(), Some(&3));
-        assert_eq!(list.peek_mut(), Some(&mut 3));
-        list.peek_mut().map(|value| *value = 42);
+        let mut list = Stack::from([1, 2]);
+        list.prepend(Stack::new());
+        assert_eq!(list.into_vec(), vec![2, 1]);
+    }
 
-        assert_eq!(list.peek(), Some(&42));
-        assert_eq!(list.pop(), Some(42));
+    #[test]
+    fn move_to_top() {
+        let mut list = Stack::from([1, 2, 3, 4]);
+        assert!(list.move_to_top(|x| *x == 2));
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.into_vec(), vec![2, 4, 3, 1]);
+
+        let mut list = Stack::from([1, 2, 3]);
+        assert!(list.move_to_top(|x| *x == 3));
+        assert_eq!(list.into_vec(), vec![3, 2, 1]);
     }
 
     #[test]
-    fn into_iter() {
-        let mut list = Stack::new();
-        list.push(1);
-        list.push(2);
-        list.push(3);
+    fn move_to_top_bottom() {
+        let mut list = Stack::from([1, 2, 3]);
+        assert!(list.move_to_top(|x| *x == 1));
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.last(), Some(&2));
+        assert_eq!(list.into_vec(), vec![1, 3, 2]);
+    }
 
-        let mut iter = list.into_iter();
-        assert_eq!(iter.next(), Some(3));
-        assert_eq!(iter.next(), Some(2));
-        assert_eq!(iter.next(), Some(1));
-        assert_eq!(iter.next(), None);
+    #[test]
+    fn move_to_top_no_match() {
+        let mut list = Stack::from([1, 2, 3]);
+        assert!(!list.move_to_top(|x| *x > 3));
+        assert_eq!(list.into_vec(), vec![3, 2, 1]);
+
+        let mut list: Stack<i32> = Stack::new();
+        assert!(!list.move_to_top(|_| true));
     }
 
     #[test]
-    fn iter() {
+    fn rfind() {
+        let list = Stack::from([(1, 'a'), (2, 'b'), (1, 'c'), (3, 'd')]);
+        assert_eq!(list.rfind(|(x, _)| *x == 1), Some(&(1, 'a')));
+        assert_eq!(list.rfind(|(x, _)| *x == 3), Some(&(3, 'd')));
+        assert_eq!(list.rfind(|(x, _)| *x == 4), None);
+        assert_eq!(Stack::<i32>::new().rfind(|_| true), None);
+    }
+
+    #[test]
+    fn sort() {
+        let mut list = Stack::from([5, 3, 9, 1, 7, 2, 8, 4, 6, 0]);
+        list.sort();
+        assert_eq!(list.len(), 10);
+        for x in 0..10 {
+            assert_eq!(list.pop(), Some(x));
+        }
+        assert_eq!(list.pop(), None);
+
+        let mut list: Stack<i32> = Stack::new();
+        list.sort();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn sort_is_stable() {
+        let mut list = Stack::from([(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (0, 'e')]);
+        list.sort_by_key(|&(key, _)| key);
+        assert_eq!(
+            list.into_vec(),
+            vec![(0, 'e'), (1, 'd'), (1, 'b'), (2, 'c'), (2, 'a')]
+        );
+    }
+
+    #[test]
+    fn from_iter() {
+        let list: Stack<i32> = (1..=3).collect();
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.into_vec(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn into_string() {
+        let list: Stack<char> = "stressed".chars().collect();
+        assert_eq!(list.into_string(), "desserts");
+
+        let list: Stack<char> = Stack::new();
+        assert_eq!(list.into_string(), "");
+    }
+
+    #[test]
+    fn truncate_extract() {
+        let mut list = Stack::from([1, 2, 3, 4, 5]);
+        assert_eq!(list.truncate_extract(3), vec![2, 1]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.truncate_extract(5), vec![]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.truncate_extract(0), vec![5, 4, 3]);
+        assert!(list.is_empty());
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn peek_or_push_with() {
         let mut list = Stack::new();
-        list.push(1);
+        assert_eq!(list.peek_or_push_with(|| 1), &mut 1);
+        assert_eq!(list.len(), 1);
+
         list.push(2);
-        list.push(3);
+        let top = list.peek_or_push_with(|| unreachable!());
+        assert_eq!(top, &mut 2);
+        *top = 3;
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.into_vec(), vec![3, 1]);
+    }
+
+    #[test]
+    fn truncate() {
+        let mut list = Stack::from([1, 2, 3, 4, 5]);
+        list.truncate(3);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.peek(), Some(&5));
+
+        list.truncate(10);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.last(), Some(&3));
+
+        list.truncate(0);
+        assert!(list.is_empty());
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn truncate_drops_values() {
+        use std::rc::Rc;
+
+        let value = Rc::new(());
+        let mut list: Stack<_> = (0..4).map(|_| Rc::clone(&value)).collect();
+        list.truncate(1);
+        assert_eq!(Rc::strong_count(&value), 2);
+    }
+
+    #[test]
+    fn split_into() {
+        let list: Stack<_> = (1..=7).collect();
+        let parts = list.split_into(3);
+        let lens: Vec<_> = parts.iter().map(Stack::len).collect();
+        assert_eq!(lens, vec![3, 2, 2]);
+
+        let parts: Vec<_> = parts.into_iter().map(Stack::into_vec).collect();
+        assert_eq!(parts, vec![vec![7, 6, 5], vec![4, 3], vec![2, 1]]);
+    }
+
+    #[test]
+    fn split_into_more_parts_than_elements() {
+        let list = Stack::from([1, 2]);
+        let mut parts = list.split_into(4);
+        assert_eq!(parts.len(), 4);
+        assert_eq!(parts[0].pop(), Some(2));
+        assert_eq!(parts[1].pop(), Some(1));
+        assert!(parts[2].is_empty());
+        assert!(parts[3].is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_into_zero_parts() {
+        Stack::from([1]).split_into(0);
+    }
+
+    #[test]
+    fn reduce() {
+        let mut list = Stack::from([1, 2, 3, 4]);
+        assert_eq!(list.reduce(|a, b| a + b), Some(10));
+        assert!(list.is_empty());
+
+        // Operands are applied in RPN order, the deeper one first
+        let mut list = Stack::from(["a", "b", "c"].map(String::from));
+        assert_eq!(list.reduce(|a, b| a + &b), Some("abc".to_string()));
+
+        let mut list = Stack::from([7]);
+        assert_eq!(list.reduce(|_, _| unreachable!()), Some(7));
+        assert!(list.is_empty());
+
+        let mut list: Stack<i32> = Stack::new();
+        assert_eq!(list.reduce(|a, b| a + b), None);
+    }
+
+    #[test]
+    fn max_by_key() {
+        let list = Stack::from([(1, 'a'), (3, 'b'), (2, 'c'), (3, 'd')]);
+        assert_eq!(list.max_by_key(|&(key, _)| key), Some(&(3, 'd')));
+        assert_eq!(list.len(), 4);
+
+        let list: Stack<i32> = Stack::new();
+        assert_eq!(list.max_by_key(|x| *x), None);
+    }
+
+    #[test]
+    fn min_by_key() {
+        let list = Stack::from([(1, 'a'), (3, 'b'), (1, 'c'), (2, 'd')]);
+        assert_eq!(list.min_by_key(|&(key, _)| key), Some(&(1, 'c')));
+        assert_eq!(list.len(), 4);
+
+        let list: Stack<i32> = Stack::new();
+        assert_eq!(list.min_by_key(|x| *x), None);
+    }
+
+    #[test]
+    fn deal() {
+        let hands = Stack::from([1, 2, 3, 4, 5, 6]).deal(3);
+        assert_eq!(hands.len(), 3);
+        assert!(hands.iter().all(|hand| hand.len() == 2));
+
+        let hands: Vec<_> = hands.into_iter().map(Stack::into_vec).collect();
+        assert_eq!(hands, vec![vec![6, 3], vec![5, 2], vec![4, 1]]);
+    }
+
+    #[test]
+    fn deal_uneven() {
+        let hands = Stack::from([1, 2, 3, 4, 5]).deal(3);
+        let lens: Vec<_> = hands.iter().map(Stack::len).collect();
+        assert_eq!(lens, vec![2, 2, 1]);
+
+        let hands: Vec<_> = hands.into_iter().map(Stack::into_vec).collect();
+        assert_eq!(hands, vec![vec![5, 2], vec![4, 1], vec![3]]);
+
+        let hands = Stack::from([1]).deal(2);
+        assert!(hands[1].is_empty());
+    }
 
+    #[test]
+    #[should_panic]
+    fn deal_zero() {
+        Stack::from([1]).deal(0);
+    }
+
+    #[test]
+    fn iter_clone() {
+        let list = Stack::from([1, 2, 3]);
         let mut iter = list.iter();
         assert_eq!(iter.next(), Some(&3));
+
+        let mut clone = iter.clone();
+        assert_eq!(clone.next(), Some(&2));
+        assert_eq!(clone.next(), Some(&1));
+        assert_eq!(clone.next(), None);
+
         assert_eq!(iter.next(), Some(&2));
         assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), None);
     }
 
     #[test]
-    fn iter_mut() {
+    fn replace_all() {
+        let mut list = Stack::from([1, 2, 1, 3, 1]);
+        assert_eq!(list.replace_all(&1, 9), 3);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.into_vec(), vec![9, 3, 9, 2, 9]);
+
+        let mut list = Stack::from([1, 2, 3]);
+        assert_eq!(list.replace_all(&4, 9), 0);
+        assert_eq!(list.into_vec(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn position() {
+        let list = Stack::from([1, 2, 3, 4]);
+        assert_eq!(list.position(|x| *x == 4), Some(0));
+        assert_eq!(list.position(|x| *x == 2), Some(2));
+        assert_eq!(list.position(|x| *x == 1), Some(3));
+        assert_eq!(list.position(|x| *x == 5), None);
+        assert_eq!(list.position(|x| x % 2 == 1), Some(1));
+    }
+
+    #[test]
+    fn cycle_values() {
+        let mut list = Stack::from([1, 2, 3, 4, 5]);
+        list.cycle_values(0);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![5, 4, 3, 2, 1]
+        );
+
+        list.cycle_values(2);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![2, 1, 5, 4, 3]
+        );
+
+        // A full cycle leaves the values in place
+        list.cycle_values(5);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![2, 1, 5, 4, 3]
+        );
+
+        list.cycle_values(8);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.into_vec(), vec![5, 4, 3, 2, 1]);
+
+        let mut list: Stack<i32> = Stack::new();
+        list.cycle_values(3);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn eq_slice() {
+        let list = Stack::from([1, 2, 3]);
+        assert!(list.eq_slice(&[3, 2, 1]));
+
+        // Length mismatch
+        assert!(!list.eq_slice(&[3, 2]));
+        assert!(!list.eq_slice(&[3, 2, 1, 0]));
+
+        // Element mismatch
+        assert!(!list.eq_slice(&[3, 4, 1]));
+        assert!(!list.eq_slice(&[1, 2, 3]));
+
+        assert!(Stack::<i32>::new().eq_slice(&[]));
+    }
+
+    #[test]
+    fn retain_mut() {
+        let mut list = Stack::from([(3, 'a'), (1, 'b'), (2, 'c'), (1, 'd')]);
+        let mut visited = vec![];
+        list.retain_mut(|(ttl, name)| {
+            visited.push(*name);
+            *ttl -= 1;
+            *ttl > 0
+        });
+        assert_eq!(visited, vec!['d', 'c', 'b', 'a']);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.into_vec(), vec![(1, 'c'), (2, 'a')]);
+    }
+
+    #[test]
+    fn capacity_hint() {
+        let mut list = Stack::new();
+        assert_eq!(list.capacity_hint(), (0, Some(0)));
+
+        for x in 1..=100 {
+            list.push(x);
+            assert_eq!(list.capacity_hint(), (x, Some(x)));
+        }
+        for x in (0..100).rev() {
+            list.pop();
+            assert_eq!(list.capacity_hint(), (x, Some(x)));
+        }
+
+        // Popping an empty stack doesn't wrap the counter around
+        assert_eq!(list.pop(), None);
+        assert_eq!(list.capacity_hint(), (0, Some(0)));
+
+        let list = Stack::from([1, 2, 3]);
+        assert_eq!(list.into_iter().size_hint(), (3, Some(3)));
+    }
+
+    #[test]
+    fn drain() {
+        let mut list = Stack::from([1, 2, 3, 4]);
+        let mut drain = list.drain();
+        assert_eq!(drain.size_hint(), (4, Some(4)));
+        assert_eq!(drain.next(), Some(4));
+        assert_eq!(drain.next(), Some(3));
+        assert_eq!(drain.len(), 2);
+        drop(drain);
+        assert!(list.is_empty());
+        assert_eq!(list.pop(), None);
+
+        list.push(5);
+        list.push(6);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.into_vec(), vec![6, 5]);
+    }
+
+    #[test]
+    fn drain_drops_unconsumed() {
+        use std::rc::Rc;
+
+        let value = Rc::new(());
+        let mut list: Stack<_> = (0..3).map(|_| Rc::clone(&value)).collect();
+        list.drain().next();
+        assert_eq!(Rc::strong_count(&value), 1);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn into_iter_rev() {
+        let mut list = Stack::new();
+        list.push("first".to_string());
+        list.push("second".to_string());
+        list.push("third".to_string());
+
+        let mut iter = list.into_iter_rev();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.next(), Some("first".to_string()));
+        assert_eq!(iter.next(), Some("second".to_string()));
+        assert_eq!(iter.next(), Some("third".to_string()));
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(Stack::<i32>::new().into_iter_rev().next(), None);
+    }
+
+    #[test]
+    fn count() {
+        let mut list = Stack::from([1, 2, 3, 4]);
+        assert_eq!(list.iter().count(), list.len());
+        assert_eq!(list.iter_mut().count(), 4);
+
+        let mut iter = list.iter();
+        iter.next();
+        assert_eq!(iter.count(), 3);
+
+        let mut iter = list.iter_mut();
+        iter.next();
+        iter.next();
+        assert_eq!(iter.count(), 2);
+
+        let mut iter = list.into_iter();
+        iter.next();
+        assert_eq!(iter.count(), 3);
+    }
+
+    #[test]
+    fn drain_count() {
+        let mut list = Stack::from([1, 2, 3]);
+        let mut drain = list.drain();
+        drain.next();
+        assert_eq!(drain.count(), 2);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn drain_filter() {
+        let mut list: Stack<_> = (1..=8).collect();
+        let removed = list.drain_filter(|x| *x % 3 == 0);
+        assert_eq!(removed, vec![6, 3]);
+        assert_eq!(list.len(), 6);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![8, 7, 5, 4, 2, 1]
+        );
+
+        // The predicate can update the kept elements
+        let removed = list.drain_filter(|x| {
+            *x *= 10;
+            *x > 50
+        });
+        assert_eq!(removed, vec![80, 70]);
+        assert_eq!(list.into_vec(), vec![50, 40, 20, 10]);
+    }
+
+    #[test]
+    fn drain_filter_none_or_all() {
+        let mut list = Stack::from([1, 2, 3]);
+        assert!(list.drain_filter(|_| false).is_empty());
+        assert_eq!(list.len(), 3);
+
+        assert_eq!(list.drain_filter(|_| true), vec![3, 2, 1]);
+        assert!(list.is_empty());
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn unique_from_bottom() {
+        let mut list = Stack::from([1, 2, 1, 3, 2, 2]);
+        list.unique_from_bottom();
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+
+        // Scanning from the top keeps the shallowest copies instead
+        let mut list = Stack::from([1, 2, 1, 3, 2, 2]);
+        let mut seen = std::collections::HashSet::new();
+        list.retain(|value| seen.insert(*value));
+        assert_eq!(list.into_vec(), vec![2, 3, 1]);
+
+        let mut list = Stack::from([4, 5, 6]);
+        list.unique_from_bottom();
+        assert_eq!(list.into_vec(), vec![6, 5, 4]);
+
+        let mut list = Stack::from([7, 7, 7]);
+        list.unique_from_bottom();
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.pop(), Some(7));
+    }
+
+    #[test]
+    fn extend() {
+        let mut list = Stack::from([1]);
+        list.extend(vec![2, 3]);
+        assert_eq!(list.len(), 3);
+
+        let source = [4, 5];
+        list.extend(&source);
+        assert_eq!(source, [4, 5]);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.into_vec(), vec![5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn bottom_mut() {
+        let mut list = Stack::from([1, 2, 3]);
+        if let Some(bottom) = list.bottom_mut() {
+            *bottom *= 100;
+        }
+        assert_eq!(list.last(), Some(&100));
+        assert_eq!(list.peek(), Some(&3));
+        assert_eq!(list.len(), 3);
+
+        let mut list = Stack::from([1]);
+        assert_eq!(list.bottom_mut(), Some(&mut 1));
+
+        let mut list: Stack<i32> = Stack::new();
+        assert_eq!(list.bottom_mut(), None);
+    }
+
+    #[test]
+    fn with_pool_preallocates() {
+        let mut list = Stack::with_pool(8);
+        let before = allocations();
+        for x in 0..8 {
+            list.push(x);
+        }
+        assert_eq!(allocations(), before);
+        assert_eq!(list.len(), 8);
+
+        // Past the preallocated nodes pushes allocate again
+        list.push(8);
+        assert_eq!(allocations(), before + 1);
+        assert_eq!(list.into_vec(), (0..9).rev().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn with_pool_recycles_popped_nodes() {
+        let mut list = Stack::with_pool(2);
+        list.push(1);
+        list.push(2);
+        list.prepend(Stack::new());
+
+        let before = allocations();
+        for x in 0..100 {
+            assert!(list.pop().is_some());
+            list.push(x);
+        }
+        assert_eq!(allocations(), before);
+
+        // Unbounded stacks don't pool
         let mut list = Stack::new();
+        list.push(1);
+        list.pop();
+        let before = allocations();
+        list.push(2);
+        assert_eq!(allocations(), before + 1);
+    }
+
+    #[test]
+    fn with_pool_drops_values_once() {
+        use std::rc::Rc;
+
+        let value = Rc::new(());
+        let mut list = Stack::with_pool(4);
+        for _ in 0..3 {
+            list.push(Rc::clone(&value));
+        }
+        drop(list.pop());
+        assert_eq!(Rc::strong_count(&value), 3);
+
+        drop(list);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn with_pool_serves_every_push() {
+        let mut list = Stack::with_pool(4);
+        let before = allocations();
+        assert_eq!(*list.peek_or_push_with(|| 1), 1);
+        list.push(2);
+        list.intersperse(0);
+        assert_eq!(allocations(), before);
+        assert_eq!(list.into_vec(), vec![2, 0, 1]);
+    }
+
+    #[test]
+    fn debug() {
+        let mut list = Stack::with_pool(2);
+        assert_eq!(format!("{list:?}"), "Stack []");
+
         list.push(1);
         list.push(2);
         list.push(3);
+        assert_eq!(format!("{list:?}"), "Stack [3, 2, 1]");
+    }
 
-        let mut iter = list.iter_mut();
-        assert_eq!(iter.next(), Some(&mut 3));
-        assert_eq!(iter.next(), Some(&mut 2));
-        assert_eq!(iter.next(), Some(&mut 1));
+    #[test]
+    fn plain_stack_has_no_pool() {
+        let list: Stack<i32> = Stack::new();
+        assert!(list.pool.is_none());
+
+        // The head, the length and a single pointer for the optional pool
+        assert_eq!(mem::size_of::<Stack<i32>>(), 3 * mem::size_of::<usize>());
     }
 }